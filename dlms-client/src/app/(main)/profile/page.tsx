@@ -622,8 +622,9 @@ export default function ProfilePage() {
                 value={hoursWorked}
                 onChange={(e) => setHoursWorked(e.target.value)}
                 required
-                min="0"
-                step="0.5"
+                min="1"
+                max="8"
+                step="1"
               />
             </div>
             <div>
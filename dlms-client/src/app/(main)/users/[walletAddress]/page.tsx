@@ -5,6 +5,7 @@ import { useParams, useRouter } from 'next/navigation';
 import { UserCheck, Star, Briefcase, CalendarClock, Tag, ArrowLeft, MapPin, FileText, Code, Globe, Award, Cake, User } from 'lucide-react';
 import Image from 'next/image';
 import { FullUserData, UserRole, getTypedUserData } from '@/types/user';
+import { RATING_SCALE } from '@/utils/format';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
 import { useAtom } from 'jotai';
 import { userAtom, currentUserAtom, allProjectsAtom } from '@/lib/atoms';
@@ -116,8 +117,9 @@ export default function UserDetailsPage() {
     });
   };
 
+  // The on-chain rating is already the average, scaled by RATING_SCALE
   const formatRating = (rating: number, count: number) => {
-    const average = count > 0 ? (rating / count / 10).toFixed(1) : '0.0';
+    const average = count > 0 ? (rating / RATING_SCALE).toFixed(1) : '0.0';
     return `${average} (${count} reviews)`;
   };
 
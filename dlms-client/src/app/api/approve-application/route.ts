@@ -96,6 +96,7 @@ import {
           application: applicationPublicKey,
           labourAccount: labourAccountPublicKey,
           project: projectPublicKey,
+          escrowAccount: projectAccount.escrowAccount,
           managerAccount: managerAccountPda,
           assignment: assignmentPda,
           authority: currentWallet,
@@ -1,13 +1,13 @@
 import { connection, program } from "@/utils/program";
 import { BN } from "@coral-xyz/anchor";
-import { createAssociatedTokenAccountInstruction, getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { createAssociatedTokenAccountInstruction, getAssociatedTokenAddress } from "@solana/spl-token";
 import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
 import { NextResponse } from "next/server";
 import bs58 from "bs58";
 
 export async function POST(req: Request) {
     try {
-        const { walletAddress, managerPda, projectPda, labourPda, dayNumber } = await req.json();
+        const { walletAddress, managerPda, projectPda, labourPda, dayNumber, hoursWorked } = await req.json();
 
 if (!walletAddress || !managerPda || !projectPda || !labourPda || !dayNumber) {
     throw new Error('One or more required addresses are missing');
@@ -20,12 +20,14 @@ if (!walletAddress || !managerPda || !projectPda || !labourPda || !dayNumber) {
         const managerPubkey = new PublicKey(managerPda);
 
 
-        const [systemStatePda] = PublicKey.findProgramAddressSync(
-            [Buffer.from("System")],
-            program.programId
-          );
-
-        const systemState = await program.account.systemState.fetch(systemStatePda);
+        // Each project escrows in its own mint, which may belong to Token-2022
+        const projectAccount = await program.account.project.fetch(projectPubkey);
+        const mint = new PublicKey(projectAccount.mint);
+        const mintInfo = await connection.getAccountInfo(mint);
+        if (!mintInfo) {
+            throw new Error('Project mint not found');
+        }
+        const tokenProgram = mintInfo.owner;
 
         const labourAccount = await program.account.userAccount.fetch(labourPda);
 
@@ -51,6 +53,10 @@ if (!walletAddress || !managerPda || !projectPda || !labourPda || !dayNumber) {
             program.programId
         );
 
+        // The manager approves the hours the labour recorded unless stated otherwise
+        const workVerification = await program.account.workVerification.fetch(workVerificationPda);
+        const approvedHours = hoursWorked !== undefined ? parseInt(hoursWorked) : workVerification.hoursWorked;
+
         // Get the escrow account
         const [escrowPda] = PublicKey.findProgramAddressSync(
             [
@@ -60,11 +66,22 @@ if (!walletAddress || !managerPda || !projectPda || !labourPda || !dayNumber) {
             program.programId
         );
 
-        const mint = new PublicKey(systemState.mint);
         const labourATA = await getAssociatedTokenAddress(
-            new PublicKey(systemState.mint),
-            labourAccount.authority
+            mint,
+            labourAccount.authority,
+            false,
+            tokenProgram
+        );
+
+        // The treasury collects the protocol fee, it is only required when the project charges one
+        const [treasuryPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("Treasury"),
+                mint.toBuffer(),
+            ],
+            program.programId
         );
+        const treasuryInfo = await connection.getAccountInfo(treasuryPda);
 
         const blockhashResponse = await program.provider.connection.getLatestBlockhash();
     	const tx = new Transaction();
@@ -78,13 +95,14 @@ if (!walletAddress || !managerPda || !projectPda || !labourPda || !dayNumber) {
                     currentWallet, // payer
                     labourATA, // associatedToken
                     labourAccount.authority, // owner
-                    mint
+                    mint,
+                    tokenProgram
                 )
             );
         }
 
          await program.methods
-            .approveWorkDay()
+            .approveWorkDay(approvedHours)
             .accounts({
                 //@ts-ignore
                 managerAccount: managerPubkey,
@@ -93,9 +111,11 @@ if (!walletAddress || !managerPda || !projectPda || !labourPda || !dayNumber) {
                 assignment: assignmentPda,
                 workVerification: workVerificationPda,
                 escrowAccount: escrowPda,
+                mint,
                 labourTokenAccount: labourATA, // This shoulde the actual token account
+                treasuryAccount: treasuryInfo ? treasuryPda : null,
                 authority: currentWallet,
-                tokenProgram: TOKEN_PROGRAM_ID,
+                tokenProgram,
                 systemProgram: SystemProgram.programId,
             })
             .instruction()
@@ -180,6 +180,13 @@ export async function POST(request: NextRequest) {
 
     const managerTokenAccount = tokenAccounts.value[0].pubkey;
 
+    // A project charging the protocol fee can only be created once the mint's treasury exists
+    const [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("Treasury"), new PublicKey(systemState.mint).toBuffer()],
+      program.programId
+    );
+    const treasuryInfo = await program.provider.connection.getAccountInfo(treasuryPda);
+
     const blockhashResponse = await program.provider.connection.getLatestBlockhash();
     const tx = new Transaction();
 
@@ -208,6 +215,7 @@ export async function POST(request: NextRequest) {
         escrowAccount: escrowAccountPda,
         managerTokenAccount: managerTokenAccount,
         mint: new PublicKey(systemState.mint),
+        treasuryAccount: treasuryInfo ? treasuryPda : null,
         authority: currentWallet,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -18,9 +18,9 @@ import {
       const body = await request.json();
   
       // Get required fields
-      const { rating, context, userAddress, reviewerAddress } = body;
+      const { scores, context, userAddress, reviewerAddress, projectPda } = body;
   
-      if (!rating || !context || !userAddress || !reviewerAddress) {
+      if (!scores || !context || !userAddress || !reviewerAddress) {
         return Response.json({
           success: false,
           error: "Missing required fields"
@@ -36,12 +36,17 @@ import {
         }, { status: 400 });
       }
   
-      // Validate rating
-      const ratingNumber = parseInt(rating);
-      if (isNaN(ratingNumber) || ratingNumber < 1 || ratingNumber > 5) {
+      // Validate every score dimension
+      const reviewScores = {
+        quality: parseInt(scores.quality),
+        punctuality: parseInt(scores.punctuality),
+        safety: parseInt(scores.safety),
+        communication: parseInt(scores.communication),
+      };
+      if (Object.values(reviewScores).some(score => isNaN(score) || score < 1 || score > 5)) {
         return Response.json({
           success: false,
-          error: "Rating must be between 1 and 5"
+          error: "Quality, punctuality, safety and communication must each be rated between 1 and 5"
         }, { status: 400 });
       }
   
@@ -51,25 +56,56 @@ import {
         program.programId
       );
   
-      // Get the review account PDA
-      const [reviewPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("Review"), new PublicKey(reviewerAddress).toBuffer(), userAccountPda.toBuffer()],
+      const currentWallet = new PublicKey(reviewerAddress);
+
+      const [reviewerAccountPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("User"), currentWallet.toBuffer()],
         program.programId
       );
 
-      const currentWallet = new PublicKey(reviewerAddress);
+      const [systemStatePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("System")],
+        program.programId
+      );
+
+      // Reviews need an assignment that shows the two users worked together, one as
+      // the labour and the other as the manager of the project
+      const sharedAssignment = await findSharedAssignment(
+        reviewerAccountPda,
+        userAccountPda,
+        projectPda ? new PublicKey(projectPda) : undefined
+      );
+
+      if (!sharedAssignment) {
+        return Response.json({
+          success: false,
+          error: "You can only rate users you have worked with on a project"
+        }, { status: 400 });
+      }
+
+      const { assignmentPda, projectPublicKey } = sharedAssignment;
+
+      // Reviews are kept per project, so the same pair can review each other once per project
+      const [reviewPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("Review"), projectPublicKey.toBuffer(), currentWallet.toBuffer(), userAccountPda.toBuffer()],
+        program.programId
+      );
   
       const blockhashResponse = await program.provider.connection.getLatestBlockhash();
     	const tx = new Transaction();
   
       await program.methods
         .rateUser(
-          ratingNumber,
+          reviewScores,
           context
         )
         .accounts({
           // @ts-ignore
+          systemState: systemStatePda,
+          reviewerAccount: reviewerAccountPda,
           userAccount: userAccountPda,
+          project: projectPublicKey,
+          assignment: assignmentPda,
           review: reviewPda,
           authority: currentWallet,
           systemProgram: SystemProgram.programId,
@@ -89,6 +125,7 @@ import {
 			blockhash: blockhashResponse.blockhash,
         serializedTransaction: base58SerializedTx,
         userAccountPda: userAccountPda.toBase58(),
+        projectPda: projectPublicKey.toBase58(),
         reviewPda: reviewPda.toBase58()
       }, {
         status: 200,
@@ -112,6 +149,46 @@ import {
     }
   }
   
+  // Finds an assignment where one user is the labour and the other manages the project,
+  // restricted to `project` when the caller names one
+  async function findSharedAssignment(
+    reviewerAccountPda: PublicKey,
+    userAccountPda: PublicKey,
+    project?: PublicKey
+  ) {
+    const pairs = [
+      { labour: reviewerAccountPda, manager: userAccountPda },
+      { labour: userAccountPda, manager: reviewerAccountPda },
+    ];
+
+    for (const { labour, manager } of pairs) {
+      const assignments = await program.account.assignment.all([
+        {
+          memcmp: {
+            offset: 8, // Skip discriminator
+            bytes: labour.toBase58(),
+          }
+        }
+      ]);
+
+      for (const assignment of assignments) {
+        if (project && !assignment.account.project.equals(project)) {
+          continue;
+        }
+
+        const projectAccount = await program.account.project.fetch(assignment.account.project);
+        if (projectAccount.manager.equals(manager)) {
+          return {
+            assignmentPda: assignment.publicKey,
+            projectPublicKey: assignment.account.project,
+          };
+        }
+      }
+    }
+
+    return null;
+  }
+
   // Handle OPTIONS request for CORS
   export async function OPTIONS() {
     return new Response(null, {
@@ -9,6 +9,9 @@ import { program } from "@/utils/program";
 import bs58 from "bs58";
 import { BN } from "@coral-xyz/anchor";
 
+// Matches HOURS_PER_DAY in the program constants
+const HOURS_PER_DAY = 8;
+
 interface WorkVerificationMetadata {
     description: string;
     hours_worked: number;
@@ -54,6 +57,16 @@ export async function POST(request: NextRequest) {
             });
         }
 
+        // Work is paid per hour up to a full day of HOURS_PER_DAY
+        if (hoursWorked < 1 || hoursWorked > HOURS_PER_DAY) {
+            return Response.json({
+                success: false,
+                error: `Hours worked must be between 1 and ${HOURS_PER_DAY}`
+            }, {
+                status: 400,
+            });
+        }
+
         // Upload work images if provided
         let workImagesUrl;
         if (workImages.length > 0) {
@@ -158,7 +171,8 @@ export async function POST(request: NextRequest) {
         await program.methods
             .verifyWorkDay(
                 dayNumber,
-                metadataUrl
+                metadataUrl,
+                hoursWorked
             )
             .accounts({
                 // @ts-ignore - Anchor types don't match exactly but the program expects these names
@@ -188,6 +202,7 @@ export async function POST(request: NextRequest) {
             manager_verified: false,
             labour_verified: true,
             metadata_uri: metadataUrl,
+            hours_worked: hoursWorked,
             timestamp: Math.floor(Date.now() / 1000),
             payment_processed: false
         };
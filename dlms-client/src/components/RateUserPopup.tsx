@@ -12,6 +12,19 @@ import { Transaction } from '@solana/web3.js';
 import bs58 from 'bs58';
 import { toast } from 'sonner';
 
+// Reviews score each of these dimensions from 1 to 5
+const SCORE_DIMENSIONS = [
+  { key: 'quality', label: 'Quality' },
+  { key: 'punctuality', label: 'Punctuality' },
+  { key: 'safety', label: 'Safety' },
+  { key: 'communication', label: 'Communication' },
+] as const;
+
+type ScoreDimension = typeof SCORE_DIMENSIONS[number]['key'];
+type ReviewScores = Record<ScoreDimension, number>;
+
+const EMPTY_SCORES: ReviewScores = { quality: 0, punctuality: 0, safety: 0, communication: 0 };
+
 interface RateUserPopupProps {
   isOpen: boolean;
   onClose: () => void;
@@ -24,14 +37,16 @@ export default function RateUserPopup({ isOpen, onClose, userName, userAddress }
   const { publicKey, signTransaction } = useWallet();
   const { connection } = useConnection();
   const isDarkMode = theme === 'dark';
-  const [rating, setRating] = useState(0);
-  const [hoverRating, setHoverRating] = useState(0);
+  const [scores, setScores] = useState<ReviewScores>(EMPTY_SCORES);
+  const [hoverScore, setHoverScore] = useState<{ key: ScoreDimension; score: number } | null>(null);
   const [review, setReview] = useState('');
   const [isSubmitting, setIsSubmitting] = useState(false);
 
+  const isComplete = SCORE_DIMENSIONS.every(({ key }) => scores[key] > 0);
+
   const handleSubmit = async (e: React.FormEvent) => {
     e.preventDefault();
-    if (rating === 0 || !publicKey || !signTransaction) return;
+    if (!isComplete || !publicKey || !signTransaction) return;
     
     setIsSubmitting(true);
     try {
@@ -42,7 +57,7 @@ export default function RateUserPopup({ isOpen, onClose, userName, userAddress }
           'Content-Type': 'application/json',
         },
         body: JSON.stringify({
-          rating,
+          scores,
           context: review,
           userAddress,
           reviewerAddress: publicKey.toBase58(),
@@ -115,25 +130,36 @@ export default function RateUserPopup({ isOpen, onClose, userName, userAddress }
           </div>
 
           <form onSubmit={handleSubmit} className="space-y-6">
-            <div className="flex justify-center gap-2">
-              {[1, 2, 3, 4, 5].map((star) => (
-                <button
-                  key={star}
-                  type="button"
-                  onClick={() => setRating(star)}
-                  onMouseEnter={() => setHoverRating(star)}
-                  onMouseLeave={() => setHoverRating(0)}
-                  className="p-1"
-                >
-                  <Star
-                    className={`w-8 h-8 ${
-                      (hoverRating || rating) >= star
-                        ? 'fill-yellow-400 text-yellow-400'
-                        : 'text-gray-300 dark:text-gray-600'
-                    }`}
-                  />
-                </button>
-              ))}
+            <div className="space-y-3">
+              {SCORE_DIMENSIONS.map(({ key, label }) => {
+                const shown = hoverScore?.key === key ? hoverScore.score : scores[key];
+
+                return (
+                  <div key={key} className="flex items-center justify-between">
+                    <span className="text-sm font-medium">{label}</span>
+                    <div className="flex gap-1">
+                      {[1, 2, 3, 4, 5].map((star) => (
+                        <button
+                          key={star}
+                          type="button"
+                          onClick={() => setScores((current) => ({ ...current, [key]: star }))}
+                          onMouseEnter={() => setHoverScore({ key, score: star })}
+                          onMouseLeave={() => setHoverScore(null)}
+                          className="p-1"
+                        >
+                          <Star
+                            className={`w-6 h-6 ${
+                              shown >= star
+                                ? 'fill-yellow-400 text-yellow-400'
+                                : 'text-gray-300 dark:text-gray-600'
+                            }`}
+                          />
+                        </button>
+                      ))}
+                    </div>
+                  </div>
+                );
+              })}
             </div>
 
             <div className="space-y-2">
@@ -152,7 +178,7 @@ export default function RateUserPopup({ isOpen, onClose, userName, userAddress }
 
             <Button
               type="submit"
-              disabled={rating === 0 || isSubmitting || !publicKey}
+              disabled={!isComplete || isSubmitting || !publicKey}
               className="w-full bg-gradient-to-r from-indigo-600 to-purple-600 hover:from-indigo-700 hover:to-purple-700 text-white"
             >
               {isSubmitting ? 'Submitting...' : 'Submit Rating'}
@@ -15,6 +15,7 @@ import { Badge } from '@/components/ui/badge';
 import { Button } from '@/components/ui/button';
 import { Tabs, TabsContent, TabsList, TabsTrigger } from '@/components/ui/tabs';
 import { UserRole, FullUserData, LaborMetadata, ManagerMetadata } from '@/types/user';
+import { formatRating, RATING_SCALE } from '@/utils/format';
 
 export default function UserDashboard({ userData }: { userData: FullUserData }) {
   const { account, metadata } = userData;
@@ -512,10 +513,10 @@ export default function UserDashboard({ userData }: { userData: FullUserData })
                   <span className="text-gray-600 dark:text-gray-300 text-sm">Overall Rating</span>
                   <div className="flex items-center">
                     <Star className="h-4 w-4 text-yellow-500 fill-yellow-500" />
-                    <span className="text-gray-900 dark:text-white font-medium ml-1">{account.rating.toFixed(1)}</span>
+                    <span className="text-gray-900 dark:text-white font-medium ml-1">{formatRating(account.rating)}</span>
                   </div>
                 </div>
-                <Progress value={(account.rating / 5) * 100} className="bg-gray-200 dark:bg-gray-800 h-2">
+                <Progress value={(account.rating / RATING_SCALE / 5) * 100} className="bg-gray-200 dark:bg-gray-800 h-2">
                   <div className="h-full bg-gradient-to-r from-yellow-500 to-yellow-400 rounded-full" />
                 </Progress>
                 <div className="text-xs text-gray-500 dark:text-gray-400 mt-1">Based on {account.rating_count} reviews</div>
@@ -6,6 +6,7 @@ import { motion, AnimatePresence } from 'framer-motion';
 import Image from 'next/image';
 import { useRouter } from 'next/navigation';
 import { FullUserData, UserRole } from '@/types/user';
+import { RATING_SCALE } from '@/utils/format';
 import { Card, CardContent } from '@/components/ui/card';
 import { useAtom } from 'jotai';
 import { userAtom } from '@/lib/atoms';
@@ -114,7 +115,7 @@ export default function UsersPage() {
 
   // Format rating to display as stars out of 5
   const formatRating = (rating: number) => {
-    return (rating / 10).toFixed(1);
+    return (rating / RATING_SCALE).toFixed(1);
   };
 
   // Navigate to user details page
@@ -13,6 +13,50 @@ export type DlmsContract = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "addAcceptedMint",
+      "discriminator": [
+        241,
+        72,
+        208,
+        73,
+        223,
+        58,
+        81,
+        69
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "systemState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        }
+      ]
+    },
     {
       "name": "addAdmin",
       "discriminator": [
@@ -59,70 +103,68 @@ export type DlmsContract = {
       ]
     },
     {
-      "name": "applyToProject",
+      "name": "addCertificationType",
       "discriminator": [
-        238,
-        235,
-        217,
-        164,
-        133,
-        8,
-        211,
-        60
+        138,
+        124,
+        205,
+        125,
+        202,
+        39,
+        66,
+        159
       ],
       "accounts": [
         {
-          "name": "labourAccount",
+          "name": "systemState",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  85,
+                  83,
+                  121,
                   115,
+                  116,
                   101,
-                  114
+                  109
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "project",
-          "writable": true
-        },
-        {
-          "name": "application",
+          "name": "certificationType",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  65,
-                  112,
-                  112,
-                  108,
+                  67,
+                  101,
+                  114,
+                  116,
+                  105,
+                  102,
                   105,
                   99,
                   97,
                   116,
                   105,
                   111,
-                  110
+                  110,
+                  84,
+                  121,
+                  112,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "labourAccount"
-              },
-              {
-                "kind": "account",
-                "path": "project"
+                "path": "system_state.certification_type_count",
+                "account": "systemState"
               }
             ]
           }
@@ -139,59 +181,44 @@ export type DlmsContract = {
       ],
       "args": [
         {
-          "name": "description",
+          "name": "name",
           "type": "string"
         }
       ]
     },
     {
-      "name": "approveApplication",
+      "name": "addMilestone",
       "discriminator": [
-        136,
-        47,
-        9,
-        33,
-        208,
-        120,
-        226,
-        157
+        165,
+        18,
+        177,
+        128,
+        204,
+        172,
+        23,
+        249
       ],
       "accounts": [
         {
-          "name": "application",
-          "writable": true,
+          "name": "managerAccount",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  65,
-                  112,
-                  112,
-                  108,
-                  105,
-                  99,
-                  97,
-                  116,
-                  105,
-                  111,
-                  110
+                  85,
+                  115,
+                  101,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "labourAccount"
-              },
-              {
-                "kind": "account",
-                "path": "project"
+                "path": "authority"
               }
             ]
           }
         },
-        {
-          "name": "labourAccount"
-        },
         {
           "name": "project",
           "writable": true,
@@ -222,49 +249,25 @@ export type DlmsContract = {
           }
         },
         {
-          "name": "managerAccount",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  85,
-                  115,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "authority"
-              }
-            ]
-          }
+          "name": "milestone",
+          "writable": true
         },
         {
-          "name": "assignment",
+          "name": "escrowAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  65,
-                  115,
+                  69,
                   115,
-                  105,
-                  103,
-                  110,
-                  109,
-                  101,
-                  110,
-                  116
+                  99,
+                  114,
+                  111,
+                  119
                 ]
               },
-              {
-                "kind": "account",
-                "path": "labourAccount"
-              },
               {
                 "kind": "account",
                 "path": "project"
@@ -272,29 +275,48 @@ export type DlmsContract = {
             ]
           }
         },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "managerTokenAccount",
+          "writable": true
+        },
         {
           "name": "authority",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "tokenProgram"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "descriptionUri",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "approveWorkDay",
+      "name": "addMilestoneNative",
       "discriminator": [
-        27,
-        247,
-        154,
-        68,
-        148,
-        68,
-        107,
-        227
+        208,
+        22,
+        157,
+        174,
+        210,
+        245,
+        128,
+        127
       ],
       "accounts": [
         {
@@ -347,41 +369,7 @@ export type DlmsContract = {
           }
         },
         {
-          "name": "labourAccount"
-        },
-        {
-          "name": "assignment",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  65,
-                  115,
-                  115,
-                  105,
-                  103,
-                  110,
-                  109,
-                  101,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "labourAccount"
-              },
-              {
-                "kind": "account",
-                "path": "project"
-              }
-            ]
-          }
-        },
-        {
-          "name": "workVerification",
+          "name": "milestone",
           "writable": true
         },
         {
@@ -392,12 +380,11 @@ export type DlmsContract = {
               {
                 "kind": "const",
                 "value": [
-                  69,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
                 ]
               },
               {
@@ -407,160 +394,199 @@ export type DlmsContract = {
             ]
           }
         },
-        {
-          "name": "labourTokenAccount",
-          "writable": true
-        },
         {
           "name": "authority",
           "writable": true,
           "signer": true
         },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
-    },
-    {
-      "name": "closeProject",
-      "discriminator": [
-        117,
-        209,
-        53,
-        106,
-        93,
-        55,
-        112,
-        49
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "descriptionUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "addSkill",
+      "discriminator": [
+        193,
+        213,
+        92,
+        116,
+        27,
+        180,
+        153,
+        6
       ],
       "accounts": [
         {
-          "name": "managerAccount",
+          "name": "systemState",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  85,
+                  83,
+                  121,
                   115,
+                  116,
                   101,
-                  114
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  107,
+                  105,
+                  108,
+                  108
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "system_state.skill_count",
+                "account": "systemState"
               }
             ]
           }
         },
         {
-          "name": "project",
+          "name": "authority",
           "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "addUserSkill",
+      "discriminator": [
+        5,
+        108,
+        115,
+        7,
+        112,
+        77,
+        227,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "userAccount",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  80,
-                  114,
-                  111,
-                  106,
+                  85,
+                  115,
                   101,
-                  99,
-                  116
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "managerAccount"
-              },
-              {
-                "kind": "account",
-                "path": "project.index",
-                "account": "project"
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "escrowAccount",
+          "name": "skillProfile",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  69,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119
+                  83,
+                  107,
+                  105,
+                  108,
+                  108,
+                  115
                 ]
               },
               {
                 "kind": "account",
-                "path": "project"
+                "path": "userAccount"
               }
             ]
           }
         },
         {
-          "name": "managerTokenAccount",
-          "writable": true
+          "name": "skill",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  107,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "skill.id",
+                "account": "skill"
+              }
+            ]
+          }
         },
         {
           "name": "authority",
-          "writable": true,
           "signer": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "status",
-          "type": {
-            "defined": {
-              "name": "projectStatus"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createProject",
+      "name": "amendProject",
       "discriminator": [
-        148,
-        219,
-        181,
-        42,
-        221,
-        114,
-        145,
-        190
+        167,
+        92,
+        89,
+        147,
+        89,
+        177,
+        202,
+        192
       ],
       "accounts": [
-        {
-          "name": "systemState",
-          "writable": true
-        },
         {
           "name": "managerAccount",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -602,8 +628,8 @@ export type DlmsContract = {
               },
               {
                 "kind": "account",
-                "path": "system_state.project_count",
-                "account": "systemState"
+                "path": "project.index",
+                "account": "project"
               }
             ]
           }
@@ -632,11 +658,11 @@ export type DlmsContract = {
           }
         },
         {
-          "name": "managerTokenAccount",
-          "writable": true
+          "name": "mint"
         },
         {
-          "name": "mint"
+          "name": "managerTokenAccount",
+          "writable": true
         },
         {
           "name": "authority",
@@ -644,61 +670,43 @@ export type DlmsContract = {
           "signer": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
+          "name": "tokenProgram"
         }
       ],
       "args": [
         {
-          "name": "title",
-          "type": "string"
-        },
-        {
-          "name": "metadataUri",
-          "type": "string"
-        },
-        {
-          "name": "dailyRate",
+          "name": "depositAmount",
           "type": "u64"
         },
         {
           "name": "durationDays",
-          "type": "u16"
+          "type": {
+            "option": "u16"
+          }
         },
         {
-          "name": "maxLabourers",
-          "type": "u8"
+          "name": "dailyRate",
+          "type": {
+            "option": "u64"
+          }
         }
       ]
     },
     {
-      "name": "deleteUser",
+      "name": "amendProjectNative",
       "discriminator": [
-        186,
-        85,
-        17,
-        249,
-        219,
-        231,
-        98,
-        251
+        111,
+        137,
+        151,
+        170,
+        14,
+        168,
+        205,
+        38
       ],
       "accounts": [
         {
-          "name": "systemState",
-          "writable": true
-        },
-        {
-          "name": "userAccount",
-          "writable": true,
+          "name": "managerAccount",
           "pda": {
             "seeds": [
               {
@@ -718,167 +726,100 @@ export type DlmsContract = {
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initializeSystem",
-      "discriminator": [
-        50,
-        173,
-        248,
-        140,
-        202,
-        35,
-        141,
-        150
-      ],
-      "accounts": [
-        {
-          "name": "systemState",
+          "name": "project",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  83,
-                  121,
-                  115,
-                  116,
+                  80,
+                  114,
+                  111,
+                  106,
                   101,
-                  109
+                  99,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
               }
             ]
           }
         },
         {
-          "name": "authority",
+          "name": "escrowAccount",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "mint",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "markUserAsSpam",
-      "discriminator": [
-        58,
-        248,
-        99,
-        200,
-        17,
-        45,
-        35,
-        240
-      ],
-      "accounts": [
-        {
-          "name": "systemState",
-          "writable": true
-        },
-        {
-          "name": "userAccount",
-          "writable": true
-        },
-        {
-          "name": "authority",
-          "signer": true,
-          "relations": [
-            "systemState"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "isSpam",
-          "type": "bool"
-        }
-      ]
-    },
-    {
-      "name": "mintToken",
-      "discriminator": [
-        172,
-        137,
-        183,
-        14,
-        207,
-        110,
-        234,
-        56
-      ],
-      "accounts": [
-        {
-          "name": "systemState",
-          "writable": true
-        },
-        {
-          "name": "mint",
-          "writable": true
-        },
-        {
-          "name": "mintAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
-                  110,
+                  86,
+                  97,
+                  117,
+                  108,
                   116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
               }
             ]
           }
         },
         {
-          "name": "to",
-          "writable": true
+          "name": "authority",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "amount",
+          "name": "depositAmount",
           "type": "u64"
+        },
+        {
+          "name": "durationDays",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "dailyRate",
+          "type": {
+            "option": "u64"
+          }
         }
       ]
     },
     {
-      "name": "rateUser",
+      "name": "applyToProject",
       "discriminator": [
-        136,
-        137,
-        93,
-        117,
-        191,
-        110,
-        35,
-        46
+        238,
+        235,
+        217,
+        164,
+        133,
+        8,
+        211,
+        60
       ],
       "accounts": [
         {
-          "name": "userAccount",
-          "writable": true,
+          "name": "labourAccount",
           "pda": {
             "seeds": [
               {
@@ -892,35 +833,43 @@ export type DlmsContract = {
               },
               {
                 "kind": "account",
-                "path": "user_account.authority",
-                "account": "userAccount"
+                "path": "authority"
               }
             ]
           }
         },
         {
-          "name": "review",
+          "name": "project",
+          "writable": true
+        },
+        {
+          "name": "application",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  82,
-                  101,
-                  118,
+                  65,
+                  112,
+                  112,
+                  108,
                   105,
-                  101,
-                  119
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "labourAccount"
               },
               {
                 "kind": "account",
-                "path": "userAccount"
+                "path": "project"
               }
             ]
           }
@@ -937,143 +886,93 @@ export type DlmsContract = {
       ],
       "args": [
         {
-          "name": "rating",
-          "type": "u8"
-        },
-        {
-          "name": "context",
+          "name": "description",
           "type": "string"
         }
       ]
     },
     {
-      "name": "registerUser",
+      "name": "approveApplication",
       "discriminator": [
-        2,
-        241,
-        150,
-        223,
-        99,
-        214,
-        116,
-        97
+        136,
+        47,
+        9,
+        33,
+        208,
+        120,
+        226,
+        157
       ],
       "accounts": [
         {
-          "name": "systemState",
-          "writable": true
-        },
-        {
-          "name": "userAccount",
+          "name": "application",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  85,
-                  115,
-                  101,
-                  114
+                  65,
+                  112,
+                  112,
+                  108,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "authority"
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "name",
-          "type": "string"
-        },
-        {
-          "name": "metadataUrl",
-          "type": "string"
-        },
-        {
-          "name": "role",
-          "type": {
-            "defined": {
-              "name": "userRole"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "removeAdmin",
-      "discriminator": [
-        74,
-        202,
-        71,
-        106,
-        252,
-        31,
-        72,
-        183
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "labourAccount"
         },
         {
-          "name": "systemState",
+          "name": "project",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  83,
-                  121,
-                  115,
-                  116,
+                  80,
+                  114,
+                  111,
+                  106,
                   101,
-                  109
+                  99,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "adminToRemove",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "updateUser",
-      "discriminator": [
-        9,
-        2,
-        160,
-        169,
-        118,
-        12,
-        207,
-        84
-      ],
-      "accounts": [
+          "name": "escrowAccount"
+        },
         {
-          "name": "userAccount",
-          "writable": true,
+          "name": "managerAccount",
           "pda": {
             "seeds": [
               {
@@ -1092,6 +991,37 @@ export type DlmsContract = {
             ]
           }
         },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
         {
           "name": "authority",
           "writable": true,
@@ -1102,66 +1032,159 @@ export type DlmsContract = {
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "name",
-          "type": "string"
-        },
-        {
-          "name": "metadataUri",
-          "type": "string"
-        },
-        {
-          "name": "active",
-          "type": {
-            "option": "bool"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "verifyUser",
+      "name": "approveMilestone",
       "discriminator": [
-        127,
-        54,
-        157,
-        106,
+        145,
         85,
-        167,
-        116,
-        119
+        92,
+        60,
+        50,
+        130,
+        219,
+        106
       ],
       "accounts": [
         {
-          "name": "systemState",
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
           "writable": true
         },
         {
-          "name": "userAccount",
+          "name": "labourAccount"
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "labourTokenAccount",
           "writable": true
         },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
         {
           "name": "authority",
           "signer": true
+        },
+        {
+          "name": "tokenProgram"
         }
       ],
       "args": []
     },
     {
-      "name": "verifyWorkDay",
+      "name": "approveMilestoneNative",
       "discriminator": [
-        143,
-        86,
-        2,
-        120,
-        149,
-        148,
-        114,
-        105
+        155,
+        134,
+        10,
+        223,
+        250,
+        247,
+        190,
+        12
       ],
       "accounts": [
         {
-          "name": "labourAccount",
+          "name": "managerAccount",
           "pda": {
             "seeds": [
               {
@@ -1182,61 +1205,7514 @@ export type DlmsContract = {
         },
         {
           "name": "project",
-          "writable": true
-        },
-        {
-          "name": "assignment",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  65,
-                  115,
-                  115,
-                  105,
-                  103,
-                  110,
-                  109,
+                  80,
+                  114,
+                  111,
+                  106,
                   101,
-                  110,
+                  99,
                   116
                 ]
               },
               {
                 "kind": "account",
-                "path": "labourAccount"
+                "path": "managerAccount"
               },
               {
                 "kind": "account",
-                "path": "project"
+                "path": "project.index",
+                "account": "project"
               }
             ]
           }
         },
         {
-          "name": "workVerification",
+          "name": "milestone",
           "writable": true
         },
         {
-          "name": "authority",
+          "name": "labourAccount"
+        },
+        {
+          "name": "escrowAccount",
           "writable": true,
-          "signer": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourWallet",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveWorkDay",
+      "discriminator": [
+        27,
+        247,
+        154,
+        68,
+        148,
+        68,
+        107,
+        227
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "labourTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "hoursWorked",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approveWorkDayNative",
+      "discriminator": [
+        162,
+        66,
+        65,
+        14,
+        166,
+        155,
+        106,
+        236
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourWallet",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "hoursWorked",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approveWorkDaysBatch",
+      "discriminator": [
+        226,
+        222,
+        119,
+        192,
+        240,
+        213,
+        122,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "hoursWorked",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "approveWorkDaysBatchNative",
+      "discriminator": [
+        8,
+        31,
+        113,
+        44,
+        254,
+        145,
+        207,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "hoursWorked",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "assignMilestone",
+      "discriminator": [
+        116,
+        218,
+        61,
+        229,
+        104,
+        229,
+        189,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        },
+        {
+          "name": "assignment",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment.labour",
+                "account": "assignment"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimWorkDay",
+      "discriminator": [
+        14,
+        0,
+        200,
+        244,
+        91,
+        60,
+        138,
+        57
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "labourTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimWorkDayNative",
+      "discriminator": [
+        205,
+        149,
+        157,
+        130,
+        203,
+        225,
+        30,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourWallet",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeApplication",
+      "discriminator": [
+        185,
+        123,
+        65,
+        93,
+        138,
+        249,
+        205,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "application",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  112,
+                  112,
+                  108,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project"
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAuthority",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeAssignment",
+      "discriminator": [
+        180,
+        122,
+        187,
+        197,
+        166,
+        152,
+        1,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount"
+        },
+        {
+          "name": "project",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "application",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  112,
+                  112,
+                  108,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification"
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "managerAuthority",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeDispute",
+      "discriminator": [
+        60,
+        18,
+        92,
+        170,
+        100,
+        195,
+        146,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  68,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute.work_verification",
+                "account": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeNativeProject",
+      "discriminator": [
+        59,
+        48,
+        200,
+        113,
+        4,
+        123,
+        84,
+        14
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "status",
+          "type": {
+            "defined": {
+              "name": "projectStatus"
+            }
+          }
+        },
+        {
+          "name": "cancellationFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeProject",
+      "discriminator": [
+        117,
+        209,
+        53,
+        106,
+        93,
+        55,
+        112,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "managerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "status",
+          "type": {
+            "defined": {
+              "name": "projectStatus"
+            }
+          }
+        },
+        {
+          "name": "cancellationFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeWorkVerification",
+      "discriminator": [
+        169,
+        40,
+        110,
+        5,
+        168,
+        194,
+        243,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "workVerification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  101,
+                  114,
+                  105,
+                  102,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "work_verification.labour",
+                "account": "workVerification"
+              },
+              {
+                "kind": "account",
+                "path": "work_verification.project",
+                "account": "workVerification"
+              },
+              {
+                "kind": "account",
+                "path": "work_verification.day_number",
+                "account": "workVerification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "completeProject",
+      "discriminator": [
+        188,
+        43,
+        115,
+        43,
+        43,
+        176,
+        30,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "confirmWorkDay",
+      "discriminator": [
+        147,
+        35,
+        206,
+        128,
+        225,
+        113,
+        174,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "labourAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "labourTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "hoursWorked",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "confirmWorkDayNative",
+      "discriminator": [
+        28,
+        124,
+        22,
+        200,
+        169,
+        27,
+        101,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "labourAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "hoursWorked",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createMilestoneProject",
+      "discriminator": [
+        211,
+        195,
+        23,
+        182,
+        123,
+        196,
+        70,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "managerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "system_state.project_count",
+                "account": "systemState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "treasuryAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "maxLabourers",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createNativeMilestoneProject",
+      "discriminator": [
+        247,
+        248,
+        23,
+        170,
+        243,
+        128,
+        86,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "managerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "system_state.project_count",
+                "account": "systemState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "maxLabourers",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createNativeProject",
+      "discriminator": [
+        49,
+        117,
+        107,
+        66,
+        144,
+        32,
+        32,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "managerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "system_state.project_count",
+                "account": "systemState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "dailyRate",
+          "type": "u64"
+        },
+        {
+          "name": "durationDays",
+          "type": "u16"
+        },
+        {
+          "name": "maxLabourers",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createProject",
+      "discriminator": [
+        148,
+        219,
+        181,
+        42,
+        221,
+        114,
+        145,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "managerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "system_state.project_count",
+                "account": "systemState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "managerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "treasuryAccount",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "dailyRate",
+          "type": "u64"
+        },
+        {
+          "name": "durationDays",
+          "type": "u16"
+        },
+        {
+          "name": "maxLabourers",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createSkillProfile",
+      "discriminator": [
+        159,
+        84,
+        158,
+        205,
+        7,
+        175,
+        76,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skillProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  107,
+                  105,
+                  108,
+                  108,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deleteUser",
+      "discriminator": [
+        186,
+        85,
+        17,
+        249,
+        219,
+        231,
+        98,
+        251
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "endorseSkill",
+      "discriminator": [
+        99,
+        171,
+        125,
+        235,
+        139,
+        212,
+        177,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skillProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  107,
+                  105,
+                  108,
+                  108,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "skill_profile.user",
+                "account": "skillProfile"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project"
+        },
+        {
+          "name": "assignment"
+        },
+        {
+          "name": "endorsement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  110,
+                  100,
+                  111,
+                  114,
+                  115,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "skillProfile"
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "skillId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "skillId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "initializeSystem",
+      "discriminator": [
+        50,
+        173,
+        248,
+        140,
+        202,
+        35,
+        141,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "initializeNativeTreasury",
+      "discriminator": [
+        118,
+        3,
+        139,
+        232,
+        12,
+        165,
+        203,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTreasury",
+      "discriminator": [
+        124,
+        186,
+        211,
+        195,
+        85,
+        165,
+        129,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "issueCertification",
+      "discriminator": [
+        151,
+        73,
+        123,
+        201,
+        36,
+        95,
+        108,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_account.authority",
+                "account": "userAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "certificationTypeAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  101,
+                  114,
+                  116,
+                  105,
+                  102,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  84,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "certificationType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  101,
+                  114,
+                  116,
+                  105,
+                  102,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userAccount"
+              },
+              {
+                "kind": "arg",
+                "path": "certificationType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "certificationType",
+          "type": "u16"
+        },
+        {
+          "name": "evidenceUri",
+          "type": "string"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "logWorkDay",
+      "discriminator": [
+        137,
+        145,
+        193,
+        203,
+        211,
+        222,
+        152,
+        177
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "dayNumber",
+          "type": "u16"
+        },
+        {
+          "name": "workMetadataUri",
+          "type": "string"
+        },
+        {
+          "name": "hoursWorked",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "markUserAsSpam",
+      "discriminator": [
+        58,
+        248,
+        99,
+        200,
+        17,
+        45,
+        35,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "isSpam",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "mintToken",
+      "discriminator": [
+        172,
+        137,
+        183,
+        14,
+        207,
+        110,
+        234,
+        56
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "mintAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "to",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "moderateReview",
+      "discriminator": [
+        153,
+        26,
+        174,
+        179,
+        90,
+        198,
+        195,
+        195
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "review",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_account.authority",
+                "account": "userAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "openDispute",
+      "discriminator": [
+        137,
+        25,
+        99,
+        119,
+        23,
+        223,
+        161,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project"
+        },
+        {
+          "name": "workVerification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  101,
+                  114,
+                  105,
+                  102,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "work_verification.labour",
+                "account": "workVerification"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              },
+              {
+                "kind": "account",
+                "path": "work_verification.day_number",
+                "account": "workVerification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  68,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "workVerification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "evidenceUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "pauseStream",
+      "discriminator": [
+        245,
+        31,
+        118,
+        229,
+        0,
+        108,
+        166,
+        82
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assignment",
+          "writable": true
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "payBonus",
+      "discriminator": [
+        215,
+        123,
+        203,
+        32,
+        15,
+        150,
+        81,
+        148
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bonus",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  66,
+                  111,
+                  110,
+                  117,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              },
+              {
+                "kind": "account",
+                "path": "assignment.bonus_count",
+                "account": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "managerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "labourTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "memoUri",
+          "type": "string"
+        },
+        {
+          "name": "source",
+          "type": {
+            "defined": {
+              "name": "bonusSource"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "payBonusNative",
+      "discriminator": [
+        58,
+        241,
+        67,
+        56,
+        2,
+        45,
+        164,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bonus",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  66,
+                  111,
+                  110,
+                  117,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              },
+              {
+                "kind": "account",
+                "path": "assignment.bonus_count",
+                "account": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourWallet",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "memoUri",
+          "type": "string"
+        },
+        {
+          "name": "source",
+          "type": {
+            "defined": {
+              "name": "bonusSource"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "payCancellationFees",
+      "discriminator": [
+        11,
+        12,
+        221,
+        107,
+        99,
+        195,
+        193,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "payCancellationFeesNative",
+      "discriminator": [
+        41,
+        224,
+        36,
+        57,
+        4,
+        41,
+        108,
+        216
+      ],
+      "accounts": [
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rateUser",
+      "discriminator": [
+        136,
+        137,
+        93,
+        117,
+        191,
+        110,
+        35,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "reviewerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_account.authority",
+                "account": "userAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project"
+        },
+        {
+          "name": "assignment"
+        },
+        {
+          "name": "review",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  82,
+                  101,
+                  118,
+                  105,
+                  101,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              },
+              {
+                "kind": "account",
+                "path": "userAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "scores",
+          "type": {
+            "defined": {
+              "name": "reviewScores"
+            }
+          }
+        },
+        {
+          "name": "context",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "refreshWeightedRating",
+      "discriminator": [
+        144,
+        249,
+        90,
+        143,
+        152,
+        210,
+        33,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "user_account.authority",
+                "account": "userAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerUser",
+      "discriminator": [
+        2,
+        241,
+        150,
+        223,
+        99,
+        214,
+        116,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "metadataUrl",
+          "type": "string"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "userRole"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "rejectApplication",
+      "discriminator": [
+        85,
+        73,
+        224,
+        47,
+        9,
+        184,
+        39,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "application",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  112,
+                  112,
+                  108,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "labourAuthority",
+          "writable": true
+        },
+        {
+          "name": "project",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "rejectMilestone",
+      "discriminator": [
+        243,
+        48,
+        66,
+        165,
+        237,
+        41,
+        116,
+        249
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "removeAcceptedMint",
+      "discriminator": [
+        71,
+        204,
+        181,
+        197,
+        187,
+        85,
+        94,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "systemState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "removeAdmin",
+      "discriminator": [
+        74,
+        202,
+        71,
+        106,
+        252,
+        31,
+        72,
+        183
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "adminToRemove",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "removeUserSkill",
+      "discriminator": [
+        203,
+        235,
+        158,
+        17,
+        130,
+        79,
+        246,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skillProfile",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  107,
+                  105,
+                  108,
+                  108,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "userAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "skill",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  107,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "skill.id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "renewCertification",
+      "discriminator": [
+        15,
+        110,
+        173,
+        37,
+        34,
+        45,
+        172,
+        38
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  101,
+                  114,
+                  116,
+                  105,
+                  102,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "certification.user",
+                "account": "certification"
+              },
+              {
+                "kind": "account",
+                "path": "certification.certification_type",
+                "account": "certification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "evidenceUri",
+          "type": "string"
+        },
+        {
+          "name": "expiresAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "resignAssignment",
+      "discriminator": [
+        144,
+        69,
+        238,
+        40,
+        149,
+        166,
+        57,
+        33
+      ],
+      "accounts": [
+        {
+          "name": "labourAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resolveDispute",
+      "discriminator": [
+        231,
+        6,
+        202,
+        6,
+        96,
+        103,
+        12,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  68,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "workVerification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "labourTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "ruling",
+          "type": {
+            "defined": {
+              "name": "disputeRuling"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveDisputeNative",
+      "discriminator": [
+        30,
+        205,
+        79,
+        205,
+        203,
+        79,
+        209,
+        238
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  68,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "workVerification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourWallet",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "ruling",
+          "type": {
+            "defined": {
+              "name": "disputeRuling"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "respondToReview",
+      "discriminator": [
+        72,
+        223,
+        211,
+        188,
+        48,
+        223,
+        39,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "review",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "responseUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "resumeStream",
+      "discriminator": [
+        193,
+        187,
+        211,
+        115,
+        119,
+        76,
+        178,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assignment",
+          "writable": true
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "retireCertificationType",
+      "discriminator": [
+        192,
+        92,
+        20,
+        204,
+        162,
+        3,
+        161,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "certificationType",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  101,
+                  114,
+                  116,
+                  105,
+                  102,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110,
+                  84,
+                  121,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "certification_type.id",
+                "account": "certificationType"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "retireSkill",
+      "discriminator": [
+        205,
+        98,
+        15,
+        193,
+        197,
+        204,
+        160,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "skill",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  107,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "skill.id",
+                "account": "skill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeCertification",
+      "discriminator": [
+        185,
+        235,
+        220,
+        63,
+        60,
+        125,
+        238,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "certification",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  67,
+                  101,
+                  114,
+                  116,
+                  105,
+                  102,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "certification.user",
+                "account": "certification"
+              },
+              {
+                "kind": "account",
+                "path": "certification.certification_type",
+                "account": "certification"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "setApprovalWindow",
+      "discriminator": [
+        83,
+        96,
+        72,
+        177,
+        64,
+        17,
+        110,
+        173
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "approvalWindow",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setFee",
+      "discriminator": [
+        18,
+        154,
+        24,
+        18,
+        237,
+        214,
+        19,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "feeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setReputationHalfLife",
+      "discriminator": [
+        32,
+        179,
+        19,
+        33,
+        53,
+        172,
+        123,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "halfLife",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setRequiredCertifications",
+      "discriminator": [
+        134,
+        68,
+        128,
+        192,
+        221,
+        204,
+        48,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "certificationTypes",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "startProject",
+      "discriminator": [
+        117,
+        24,
+        51,
+        74,
+        50,
+        141,
+        55,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "startStream",
+      "discriminator": [
+        12,
+        48,
+        99,
+        2,
+        49,
+        101,
+        104,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification"
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "stopStream",
+      "discriminator": [
+        55,
+        217,
+        35,
+        58,
+        220,
+        18,
+        146,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assignment",
+          "writable": true
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitDisputeEvidence",
+      "discriminator": [
+        177,
+        174,
+        100,
+        125,
+        106,
+        213,
+        241,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "userAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project"
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  68,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute.work_verification",
+                "account": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "evidenceUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "submitMilestone",
+      "discriminator": [
+        35,
+        96,
+        220,
+        215,
+        102,
+        83,
+        139,
+        52
+      ],
+      "accounts": [
+        {
+          "name": "labourAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true
+        },
+        {
+          "name": "assignment",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "submissionUri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "terminateAssignment",
+      "discriminator": [
+        141,
+        89,
+        238,
+        107,
+        250,
+        24,
+        17,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "labourTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "terminationReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "terminateAssignmentNative",
+      "discriminator": [
+        114,
+        144,
+        176,
+        211,
+        174,
+        197,
+        102,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "managerAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "managerAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourAccount"
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  86,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "labourWallet",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "terminationReason"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateUser",
+      "discriminator": [
+        9,
+        2,
+        160,
+        169,
+        118,
+        12,
+        207,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "userAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "active",
+          "type": {
+            "option": "bool"
+          }
+        }
+      ]
+    },
+    {
+      "name": "verifyUser",
+      "discriminator": [
+        127,
+        54,
+        157,
+        106,
+        85,
+        167,
+        116,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "verifyWorkDay",
+      "discriminator": [
+        143,
+        86,
+        2,
+        120,
+        149,
+        148,
+        114,
+        105
+      ],
+      "accounts": [
+        {
+          "name": "labourAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true
+        },
+        {
+          "name": "assignment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "workVerification",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
+      "args": [
+        {
+          "name": "dayNumber",
+          "type": "u16"
+        },
+        {
+          "name": "workMetadataUri",
+          "type": "string"
+        },
+        {
+          "name": "hoursWorked",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "withdrawApplication",
+      "discriminator": [
+        222,
+        82,
+        83,
+        147,
+        78,
+        67,
+        50,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "labourAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project"
+        },
+        {
+          "name": "application",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  112,
+                  112,
+                  108,
+                  105,
+                  99,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawNativeTreasury",
+      "discriminator": [
+        18,
+        203,
+        60,
+        138,
+        142,
+        167,
+        166,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  78,
+                  97,
+                  116,
+                  105,
+                  118,
+                  101,
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawStream",
+      "discriminator": [
+        211,
+        21,
+        90,
+        92,
+        185,
+        214,
+        88,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "labourAccount",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  85,
+                  115,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "project",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  80,
+                  114,
+                  111,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.manager",
+                "account": "project"
+              },
+              {
+                "kind": "account",
+                "path": "project.index",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "assignment",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  65,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "labourAccount"
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stream",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  116,
+                  114,
+                  101,
+                  97,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  69,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "labourTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "treasuryAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "project.mint",
+                "account": "project"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTreasury",
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
         {
-          "name": "dayNumber",
-          "type": "u16"
+          "name": "systemState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  83,
+                  121,
+                  115,
+                  116,
+                  101,
+                  109
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "workMetadataUri",
-          "type": "string"
+          "name": "treasuryAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  84,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "treasury_account.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "destinationTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "systemState"
+          ]
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
         }
       ]
     }
@@ -1256,16 +8732,120 @@ export type DlmsContract = {
       ]
     },
     {
-      "name": "assignment",
+      "name": "assignment",
+      "discriminator": [
+        106,
+        201,
+        110,
+        51,
+        89,
+        170,
+        73,
+        31
+      ]
+    },
+    {
+      "name": "bonus",
+      "discriminator": [
+        198,
+        155,
+        31,
+        171,
+        113,
+        36,
+        189,
+        12
+      ]
+    },
+    {
+      "name": "certification",
+      "discriminator": [
+        141,
+        130,
+        166,
+        168,
+        167,
+        23,
+        163,
+        147
+      ]
+    },
+    {
+      "name": "certificationType",
+      "discriminator": [
+        193,
+        159,
+        250,
+        216,
+        157,
+        254,
+        232,
+        161
+      ]
+    },
+    {
+      "name": "dispute",
+      "discriminator": [
+        36,
+        49,
+        241,
+        67,
+        40,
+        36,
+        241,
+        74
+      ]
+    },
+    {
+      "name": "endorsement",
+      "discriminator": [
+        167,
+        137,
+        37,
+        17,
+        220,
+        102,
+        104,
+        52
+      ]
+    },
+    {
+      "name": "milestone",
+      "discriminator": [
+        38,
+        210,
+        239,
+        177,
+        85,
+        184,
+        10,
+        44
+      ]
+    },
+    {
+      "name": "nativeEscrow",
+      "discriminator": [
+        169,
+        64,
+        4,
+        91,
+        81,
+        172,
+        222,
+        37
+      ]
+    },
+    {
+      "name": "nativeTreasury",
       "discriminator": [
-        106,
-        201,
-        110,
-        51,
-        89,
-        170,
+        3,
+        63,
+        138,
+        4,
         73,
-        31
+        171,
+        169,
+        159
       ]
     },
     {
@@ -1294,6 +8874,45 @@ export type DlmsContract = {
         15
       ]
     },
+    {
+      "name": "skill",
+      "discriminator": [
+        53,
+        13,
+        242,
+        204,
+        77,
+        249,
+        1,
+        215
+      ]
+    },
+    {
+      "name": "skillProfile",
+      "discriminator": [
+        94,
+        64,
+        29,
+        112,
+        42,
+        251,
+        188,
+        214
+      ]
+    },
+    {
+      "name": "stream",
+      "discriminator": [
+        166,
+        224,
+        59,
+        4,
+        202,
+        10,
+        186,
+        83
+      ]
+    },
     {
       "name": "systemState",
       "discriminator": [
@@ -1401,277 +9020,1223 @@ export type DlmsContract = {
       "msg": "Project is full"
     },
     {
-      "code": 6013,
-      "name": "labourNotActive",
-      "msg": "Labour is not active"
+      "code": 6013,
+      "name": "labourNotActive",
+      "msg": "Labour is not active"
+    },
+    {
+      "code": 6014,
+      "name": "applicationNotPending",
+      "msg": "Application is not pending"
+    },
+    {
+      "code": 6015,
+      "name": "invalidProject",
+      "msg": "Invalid project"
+    },
+    {
+      "code": 6016,
+      "name": "invalidDaySequence",
+      "msg": "Working day not matching"
+    },
+    {
+      "code": 6017,
+      "name": "invalidLabour",
+      "msg": "Invalid labour"
+    },
+    {
+      "code": 6018,
+      "name": "invalidManager",
+      "msg": "Invalid manager"
+    },
+    {
+      "code": 6019,
+      "name": "wrongProjectStatus",
+      "msg": "Wrong Project Status"
+    },
+    {
+      "code": 6020,
+      "name": "projectNotActive",
+      "msg": "Project is not active"
+    },
+    {
+      "code": 6021,
+      "name": "projectStillActive",
+      "msg": "Project is still active"
+    },
+    {
+      "code": 6022,
+      "name": "assignmentNotActive",
+      "msg": "Assignment is not active"
+    },
+    {
+      "code": 6023,
+      "name": "invalidDayNumber",
+      "msg": "Invalid day number"
+    },
+    {
+      "code": 6024,
+      "name": "alreadyVerified",
+      "msg": "Work already verified"
+    },
+    {
+      "code": 6025,
+      "name": "invalidRating",
+      "msg": "Invalid rating value (must be 1-5)"
+    },
+    {
+      "code": 6026,
+      "name": "invalidEscrowAccount",
+      "msg": "Invalid escrow account"
+    },
+    {
+      "code": 6027,
+      "name": "invalidTokenMint",
+      "msg": "Invalid token mint provided."
+    },
+    {
+      "code": 6028,
+      "name": "mintMismatch",
+      "msg": "The mint account does not match the system's configured mint."
+    },
+    {
+      "code": 6029,
+      "name": "reasonTooLong",
+      "msg": "Reason is too long"
+    },
+    {
+      "code": 6030,
+      "name": "workNotVerified",
+      "msg": "Work day has not been verified by labour"
+    },
+    {
+      "code": 6031,
+      "name": "paymentAlreadyProcessed",
+      "msg": "Payment already processed"
+    },
+    {
+      "code": 6032,
+      "name": "workDayRejected",
+      "msg": "Work day was rejected"
+    },
+    {
+      "code": 6033,
+      "name": "disputeOpen",
+      "msg": "Work day is under dispute"
+    },
+    {
+      "code": 6034,
+      "name": "disputeNotOpen",
+      "msg": "Dispute is not open"
+    },
+    {
+      "code": 6035,
+      "name": "noLabourers",
+      "msg": "Project has no labourers"
+    },
+    {
+      "code": 6036,
+      "name": "unsettledWorkDays",
+      "msg": "Project still has unsettled work days"
+    },
+    {
+      "code": 6037,
+      "name": "invalidWorkVerification",
+      "msg": "Invalid work verification account"
+    },
+    {
+      "code": 6038,
+      "name": "wrongPaymentMode",
+      "msg": "Instruction does not match the project payment mode"
+    },
+    {
+      "code": 6039,
+      "name": "invalidMilestoneAmount",
+      "msg": "Invalid milestone amount"
+    },
+    {
+      "code": 6040,
+      "name": "milestoneNotAssigned",
+      "msg": "Milestone is not assigned to this labour"
+    },
+    {
+      "code": 6041,
+      "name": "milestoneNotSubmitted",
+      "msg": "Milestone has not been submitted"
+    },
+    {
+      "code": 6042,
+      "name": "invalidRemainingAccounts",
+      "msg": "Invalid remaining accounts"
+    },
+    {
+      "code": 6043,
+      "name": "workNotLogged",
+      "msg": "Work day has not been logged by the manager"
+    },
+    {
+      "code": 6044,
+      "name": "invalidHoursWorked",
+      "msg": "Invalid hours worked"
+    },
+    {
+      "code": 6045,
+      "name": "hoursMismatch",
+      "msg": "Hours worked do not match the verification"
+    },
+    {
+      "code": 6046,
+      "name": "invalidBonusAmount",
+      "msg": "Invalid bonus amount"
+    },
+    {
+      "code": 6047,
+      "name": "insufficientEscrowSurplus",
+      "msg": "Escrow surplus is too small for this payment"
+    },
+    {
+      "code": 6048,
+      "name": "invalidFee",
+      "msg": "Fee is above the allowed maximum"
+    },
+    {
+      "code": 6049,
+      "name": "mintNotAccepted",
+      "msg": "Mint is not accepted for payments"
+    },
+    {
+      "code": 6050,
+      "name": "mintAlreadyAccepted",
+      "msg": "Mint is already accepted"
+    },
+    {
+      "code": 6051,
+      "name": "mintLimitReached",
+      "msg": "Accepted mint limit reached"
+    },
+    {
+      "code": 6052,
+      "name": "invalidApprovalWindow",
+      "msg": "Invalid approval window"
+    },
+    {
+      "code": 6053,
+      "name": "approvalWindowNotElapsed",
+      "msg": "Approval window has not elapsed yet"
+    },
+    {
+      "code": 6054,
+      "name": "wrongEscrowAsset",
+      "msg": "Project escrow does not hold this asset"
+    },
+    {
+      "code": 6055,
+      "name": "assignmentStreaming",
+      "msg": "Assignment is paid through a wage stream"
+    },
+    {
+      "code": 6056,
+      "name": "streamPaused",
+      "msg": "Stream is paused"
+    },
+    {
+      "code": 6057,
+      "name": "streamNotPaused",
+      "msg": "Stream is not paused"
+    },
+    {
+      "code": 6058,
+      "name": "streamStopped",
+      "msg": "Stream has been stopped"
+    },
+    {
+      "code": 6059,
+      "name": "nothingToWithdraw",
+      "msg": "Nothing to withdraw"
+    },
+    {
+      "code": 6060,
+      "name": "workDayNotSettled",
+      "msg": "Work day is neither paid nor rejected"
+    },
+    {
+      "code": 6061,
+      "name": "applicationStillPending",
+      "msg": "Application is still pending"
+    },
+    {
+      "code": 6062,
+      "name": "assignmentStillActive",
+      "msg": "Assignment is still active"
+    },
+    {
+      "code": 6063,
+      "name": "streamNotSettled",
+      "msg": "Stream still holds unpaid wages"
+    },
+    {
+      "code": 6064,
+      "name": "streamsStillRunning",
+      "msg": "Wage streams must be stopped first"
+    },
+    {
+      "code": 6065,
+      "name": "noWorkingRelationship",
+      "msg": "Users have not worked together on this project"
+    },
+    {
+      "code": 6066,
+      "name": "reviewAlreadyAnswered",
+      "msg": "Review already has a response"
+    },
+    {
+      "code": 6067,
+      "name": "reviewHidden",
+      "msg": "Review is already hidden"
+    },
+    {
+      "code": 6068,
+      "name": "invalidReviewee",
+      "msg": "Account is not the reviewee of this review"
+    },
+    {
+      "code": 6069,
+      "name": "invalidHalfLife",
+      "msg": "Invalid reputation half-life"
+    },
+    {
+      "code": 6070,
+      "name": "skillNameTooLong",
+      "msg": "Skill name is too long"
+    },
+    {
+      "code": 6071,
+      "name": "skillRetired",
+      "msg": "Skill has been retired"
+    },
+    {
+      "code": 6072,
+      "name": "skillAlreadyAdded",
+      "msg": "Skill is already on the profile"
+    },
+    {
+      "code": 6073,
+      "name": "skillLimitReached",
+      "msg": "Skill limit reached"
+    },
+    {
+      "code": 6074,
+      "name": "skillNotOnProfile",
+      "msg": "Skill is not on the profile"
+    },
+    {
+      "code": 6075,
+      "name": "invalidCertificationExpiry",
+      "msg": "Certification expiry must be in the future"
+    },
+    {
+      "code": 6076,
+      "name": "certificationRevoked",
+      "msg": "Certification has been revoked"
+    },
+    {
+      "code": 6077,
+      "name": "tooManyCertifications",
+      "msg": "Too many required certifications"
+    },
+    {
+      "code": 6078,
+      "name": "missingCertification",
+      "msg": "Labour is missing a required certification"
+    },
+    {
+      "code": 6079,
+      "name": "treasuryRequired",
+      "msg": "Treasury account is required when a protocol fee is charged"
+    },
+    {
+      "code": 6080,
+      "name": "streamRequired",
+      "msg": "The stream account must be passed for a streaming assignment"
+    },
+    {
+      "code": 6081,
+      "name": "applicationAccepted",
+      "msg": "An accepted application is kept until its assignment exists or the project ends"
+    },
+    {
+      "code": 6082,
+      "name": "projectAlreadyClosed",
+      "msg": "Project is already completed or cancelled"
+    },
+    {
+      "code": 6083,
+      "name": "cancellationFeeAlreadyPaid",
+      "msg": "Cancellation fee was already paid for this assignment"
+    },
+    {
+      "code": 6084,
+      "name": "noCancellationFee",
+      "msg": "Project was cancelled without a cancellation fee"
+    },
+    {
+      "code": 6085,
+      "name": "invalidResponseUri",
+      "msg": "Response URI must be between 1 and 250 characters"
+    },
+    {
+      "code": 6086,
+      "name": "evidenceUriTooLong",
+      "msg": "Evidence URI must be at most 250 characters"
+    },
+    {
+      "code": 6087,
+      "name": "certificationNameTooLong",
+      "msg": "Certification type name is too long"
+    },
+    {
+      "code": 6088,
+      "name": "certificationTypeRetired",
+      "msg": "Certification type has been retired"
+    },
+    {
+      "code": 6089,
+      "name": "invalidCertificationType",
+      "msg": "Certification type does not match the registry"
+    },
+    {
+      "code": 6090,
+      "name": "cancellationFeeNotPaid",
+      "msg": "Cancellation fee has not been paid to this labour"
+    },
+    {
+      "code": 6091,
+      "name": "applicationAlreadyClosed",
+      "msg": "Application was closed before the assignment while the project is running"
+    },
+    {
+      "code": 6092,
+      "name": "disputeNotResolved",
+      "msg": "Dispute has not been resolved"
+    },
+    {
+      "code": 6093,
+      "name": "workMetadataUriTooLong",
+      "msg": "Work metadata URI must be at most 250 characters"
+    },
+    {
+      "code": 6094,
+      "name": "unsettledMilestones",
+      "msg": "Submitted milestones must be approved or rejected first"
+    },
+    {
+      "code": 6095,
+      "name": "memoUriTooLong",
+      "msg": "Memo URI must be at most 250 characters"
+    },
+    {
+      "code": 6096,
+      "name": "milestoneUriTooLong",
+      "msg": "Milestone URI must be at most 250 characters"
+    },
+    {
+      "code": 6097,
+      "name": "cancellationFeesAlreadyReserved",
+      "msg": "Cancellation fees were already reserved for this project"
+    }
+  ],
+  "types": [
+    {
+      "name": "application",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "labour",
+            "type": "pubkey"
+          },
+          {
+            "name": "project",
+            "type": "pubkey"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "applicationStatus"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "applicationStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "accepted"
+          },
+          {
+            "name": "rejected"
+          },
+          {
+            "name": "withdrawn"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "applicationNotPending",
-      "msg": "Application is not pending"
+      "name": "assignment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "labour",
+            "type": "pubkey"
+          },
+          {
+            "name": "project",
+            "type": "pubkey"
+          },
+          {
+            "name": "daysWorked",
+            "type": "u16"
+          },
+          {
+            "name": "daysPaid",
+            "type": "u16"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "endedAt",
+            "type": "i64"
+          },
+          {
+            "name": "terminationReason",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "terminationReason"
+                }
+              }
+            }
+          },
+          {
+            "name": "resigned",
+            "type": "bool"
+          },
+          {
+            "name": "bonusCount",
+            "type": "u16"
+          },
+          {
+            "name": "bonusTotal",
+            "type": "u64"
+          },
+          {
+            "name": "streaming",
+            "type": "bool"
+          },
+          {
+            "name": "cancellationFeePaid",
+            "type": "bool"
+          },
+          {
+            "name": "daysClaimed",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "invalidProject",
-      "msg": "Invalid project"
+      "name": "bonus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assignment",
+            "type": "pubkey"
+          },
+          {
+            "name": "project",
+            "type": "pubkey"
+          },
+          {
+            "name": "labour",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "memoUri",
+            "type": "string"
+          },
+          {
+            "name": "source",
+            "type": {
+              "defined": {
+                "name": "bonusSource"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "invalidDaySequence",
-      "msg": "Working day not matching"
+      "name": "bonusSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "escrowSurplus"
+          },
+          {
+            "name": "managerAccount"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "invalidLabour",
-      "msg": "Invalid labour"
+      "name": "certification",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "certificationType",
+            "type": "u16"
+          },
+          {
+            "name": "issuer",
+            "type": "pubkey"
+          },
+          {
+            "name": "evidenceUri",
+            "type": "string"
+          },
+          {
+            "name": "issuedAt",
+            "type": "i64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "revoked",
+            "type": "bool"
+          },
+          {
+            "name": "revokedAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "invalidManager",
-      "msg": "Invalid manager"
+      "name": "certificationType",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "wrongProjectStatus",
-      "msg": "Wrong Project Status"
+      "name": "dispute",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "workVerification",
+            "type": "pubkey"
+          },
+          {
+            "name": "project",
+            "type": "pubkey"
+          },
+          {
+            "name": "labour",
+            "type": "pubkey"
+          },
+          {
+            "name": "openedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "labourEvidenceUri",
+            "type": "string"
+          },
+          {
+            "name": "managerEvidenceUri",
+            "type": "string"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "disputeStatus"
+              }
+            }
+          },
+          {
+            "name": "ruling",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "disputeRuling"
+                }
+              }
+            }
+          },
+          {
+            "name": "resolvedBy",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "resolvedAt",
+            "type": "i64"
+          },
+          {
+            "name": "rentPayer",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "projectNotActive",
-      "msg": "Project is not active"
+      "name": "disputeRuling",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "payLabour"
+          },
+          {
+            "name": "rejectDay"
+          },
+          {
+            "name": "split"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "projectStillActive",
-      "msg": "Project is still active"
+      "name": "disputeStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "open"
+          },
+          {
+            "name": "resolved"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "assignmentNotActive",
-      "msg": "Assignment is not active"
+      "name": "endorsement",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "skillProfile",
+            "type": "pubkey"
+          },
+          {
+            "name": "skillId",
+            "type": "u16"
+          },
+          {
+            "name": "endorser",
+            "type": "pubkey"
+          },
+          {
+            "name": "project",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "invalidDayNumber",
-      "msg": "Invalid day number"
+      "name": "escrowAsset",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "token"
+          },
+          {
+            "name": "native"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "alreadyVerified",
-      "msg": "Work already verified"
+      "name": "milestone",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "project",
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "descriptionUri",
+            "type": "string"
+          },
+          {
+            "name": "assignee",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "submissionUri",
+            "type": "string"
+          },
+          {
+            "name": "labourVerified",
+            "type": "bool"
+          },
+          {
+            "name": "managerVerified",
+            "type": "bool"
+          },
+          {
+            "name": "paymentProcessed",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "invalidRating",
-      "msg": "Invalid rating value (must be 1-5)"
+      "name": "nativeEscrow",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "project",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "invalidEscrowAccount",
-      "msg": "Invalid escrow account"
+      "name": "paymentMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "dailyRate"
+          },
+          {
+            "name": "milestone"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "invalidTokenMint",
-      "msg": "Invalid token mint provided."
+      "name": "nativeTreasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "systemState",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "mintMismatch",
-      "msg": "The mint account does not match the system's configured mint."
-    }
-  ],
-  "types": [
-    {
-      "name": "application",
+      "name": "project",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "labour",
+            "name": "manager",
             "type": "pubkey"
           },
           {
-            "name": "project",
-            "type": "pubkey"
+            "name": "title",
+            "type": "string"
           },
           {
-            "name": "description",
+            "name": "metadataUri",
             "type": "string"
           },
+          {
+            "name": "dailyRate",
+            "type": "u64"
+          },
+          {
+            "name": "durationDays",
+            "type": "u16"
+          },
+          {
+            "name": "maxLabourers",
+            "type": "u8"
+          },
+          {
+            "name": "labourCount",
+            "type": "u8"
+          },
           {
             "name": "status",
             "type": {
               "defined": {
-                "name": "applicationStatus"
+                "name": "projectStatus"
+              }
+            }
+          },
+          {
+            "name": "escrowAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "pendingVerifications",
+            "type": "u16"
+          },
+          {
+            "name": "daysPaid",
+            "type": "u32"
+          },
+          {
+            "name": "paymentMode",
+            "type": {
+              "defined": {
+                "name": "paymentMode"
+              }
+            }
+          },
+          {
+            "name": "milestoneCount",
+            "type": "u8"
+          },
+          {
+            "name": "milestoneTotal",
+            "type": "u64"
+          },
+          {
+            "name": "milestonePaid",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "escrowAsset",
+            "type": {
+              "defined": {
+                "name": "escrowAsset"
               }
             }
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "streamedPaid",
+            "type": "u64"
+          },
+          {
+            "name": "pendingMilestoneTotal",
+            "type": "u64"
+          },
+          {
+            "name": "activeStreams",
+            "type": "u8"
+          },
+          {
+            "name": "streamOwed",
+            "type": "u64"
+          },
+          {
+            "name": "requiredCertifications",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "cancellationFee",
+            "type": "u64"
+          },
+          {
+            "name": "dailyLabourers",
+            "type": "u8"
+          },
+          {
+            "name": "claimedDays",
+            "type": "u32"
+          },
+          {
+            "name": "streamCommitted",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "applicationStatus",
+      "name": "projectStatus",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "pending"
+            "name": "open"
           },
           {
-            "name": "accepted"
+            "name": "inProgress"
           },
           {
-            "name": "rejected"
+            "name": "completed"
           },
           {
-            "name": "withdrawn"
+            "name": "cancelled"
           }
         ]
       }
     },
     {
-      "name": "assignment",
+      "name": "reputation",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "labour",
-            "type": "pubkey"
-          },
-          {
-            "name": "project",
-            "type": "pubkey"
-          },
-          {
-            "name": "daysWorked",
-            "type": "u16"
+            "name": "quality",
+            "type": "u64"
           },
           {
-            "name": "daysPaid",
-            "type": "u16"
+            "name": "punctuality",
+            "type": "u64"
           },
           {
-            "name": "active",
-            "type": "bool"
+            "name": "safety",
+            "type": "u64"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "communication",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "project",
+      "name": "review",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "manager",
+            "name": "reviewer",
             "type": "pubkey"
           },
           {
-            "name": "title",
-            "type": "string"
-          },
-          {
-            "name": "metadataUri",
-            "type": "string"
+            "name": "reviewee",
+            "type": "pubkey"
           },
           {
-            "name": "dailyRate",
-            "type": "u64"
+            "name": "project",
+            "type": "pubkey"
           },
           {
-            "name": "durationDays",
-            "type": "u16"
+            "name": "scores",
+            "type": {
+              "defined": {
+                "name": "reviewScores"
+              }
+            }
           },
           {
-            "name": "maxLabourers",
-            "type": "u8"
+            "name": "context",
+            "type": "string"
           },
           {
-            "name": "labourCount",
-            "type": "u8"
+            "name": "timestamp",
+            "type": "i64"
           },
           {
-            "name": "status",
+            "name": "reviewType",
             "type": {
               "defined": {
-                "name": "projectStatus"
+                "name": "reviewType"
               }
             }
           },
           {
-            "name": "escrowAccount",
-            "type": "pubkey"
+            "name": "responseUri",
+            "type": "string"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
+            "name": "hidden",
+            "type": "bool"
           },
           {
-            "name": "index",
-            "type": "u32"
+            "name": "moderationReason",
+            "type": "string"
+          },
+          {
+            "name": "moderatedBy",
+            "type": {
+              "option": "pubkey"
+            }
           }
         ]
       }
     },
     {
-      "name": "projectStatus",
+      "name": "reviewScores",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "open"
+            "name": "quality",
+            "type": "u8"
           },
           {
-            "name": "inProgress"
+            "name": "punctuality",
+            "type": "u8"
           },
           {
-            "name": "completed"
+            "name": "safety",
+            "type": "u8"
           },
           {
-            "name": "cancelled"
+            "name": "communication",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "review",
+      "name": "reviewType",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "reviewer",
-            "type": "pubkey"
+            "name": "labourReview"
           },
           {
-            "name": "reviewee",
-            "type": "pubkey"
-          },
+            "name": "managerReview"
+          }
+        ]
+      }
+    },
+    {
+      "name": "skill",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "rating",
-            "type": "u8"
+            "name": "id",
+            "type": "u16"
           },
           {
-            "name": "context",
+            "name": "name",
             "type": "string"
           },
+          {
+            "name": "active",
+            "type": "bool"
+          },
           {
             "name": "timestamp",
             "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "skillProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
           },
           {
-            "name": "reviewType",
+            "name": "skills",
             "type": {
-              "defined": {
-                "name": "reviewType"
+              "vec": {
+                "defined": {
+                  "name": "userSkill"
+                }
               }
             }
           }
@@ -1679,15 +10244,53 @@ export type DlmsContract = {
       }
     },
     {
-      "name": "reviewType",
+      "name": "stream",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "labourReview"
+            "name": "assignment",
+            "type": "pubkey"
           },
           {
-            "name": "managerReview"
+            "name": "project",
+            "type": "pubkey"
+          },
+          {
+            "name": "labour",
+            "type": "pubkey"
+          },
+          {
+            "name": "ratePerDay",
+            "type": "u64"
+          },
+          {
+            "name": "cap",
+            "type": "u64"
+          },
+          {
+            "name": "accrued",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "lastCheckpoint",
+            "type": "i64"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "stopped",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
@@ -1722,6 +10325,55 @@ export type DlmsContract = {
             "type": {
               "vec": "pubkey"
             }
+          },
+          {
+            "name": "approvalWindow",
+            "type": "i64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "name": "acceptedMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "reputationHalfLife",
+            "type": "i64"
+          },
+          {
+            "name": "skillCount",
+            "type": "u16"
+          },
+          {
+            "name": "certificationTypeCount",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "terminationReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "misconduct"
+          },
+          {
+            "name": "noShow"
+          },
+          {
+            "name": "performance"
+          },
+          {
+            "name": "scaleDown"
+          },
+          {
+            "name": "other"
           }
         ]
       }
@@ -1759,6 +10411,30 @@ export type DlmsContract = {
             "name": "ratingCount",
             "type": "u32"
           },
+          {
+            "name": "reputation",
+            "type": {
+              "defined": {
+                "name": "reputation"
+              }
+            }
+          },
+          {
+            "name": "weightedRating",
+            "type": "u32"
+          },
+          {
+            "name": "weightedRatingAt",
+            "type": "i64"
+          },
+          {
+            "name": "decayedScoreSum",
+            "type": "u128"
+          },
+          {
+            "name": "decayedWeight",
+            "type": "u128"
+          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -1796,6 +10472,22 @@ export type DlmsContract = {
         ]
       }
     },
+    {
+      "name": "userSkill",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "skillId",
+            "type": "u16"
+          },
+          {
+            "name": "endorsements",
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
       "name": "workVerification",
       "type": {
@@ -1813,6 +10505,10 @@ export type DlmsContract = {
             "name": "dayNumber",
             "type": "u16"
           },
+          {
+            "name": "hoursWorked",
+            "type": "u8"
+          },
           {
             "name": "managerVerified",
             "type": "bool"
@@ -1832,6 +10528,18 @@ export type DlmsContract = {
           {
             "name": "paymentProcessed",
             "type": "bool"
+          },
+          {
+            "name": "disputed",
+            "type": "bool"
+          },
+          {
+            "name": "rejected",
+            "type": "bool"
+          },
+          {
+            "name": "rentPayer",
+            "type": "pubkey"
           }
         ]
       }
@@ -40,10 +40,18 @@ export type Assignment = {
 // Review Types
 export type ReviewType = "labourReview" | "managerReview";
 
+export type ReviewScores = {
+  quality: number;
+  punctuality: number;
+  safety: number;
+  communication: number;
+};
+
 export type Review = {
   reviewer: string;
   reviewee: string;
-  rating: number;
+  project: string;
+  scores: ReviewScores;
   context: string;
   timestamp: bigint;
   reviewType: ReviewType;
@@ -1,3 +1,10 @@
+// Ratings are stored on chain as fixed-point averages scaled by RATING_SCALE
+export const RATING_SCALE = 100;
+
+export function formatRating(rating: number): string {
+  return (rating / RATING_SCALE).toFixed(1);
+}
+
 export function formatRelativeTime(timestamp: number): string {
   const now = Date.now();
   const diff = now - timestamp;
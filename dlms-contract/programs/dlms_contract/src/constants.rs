@@ -9,11 +9,45 @@ pub const APPLICATION: &str = "Application";
 pub const ASSIGNMENT: &str = "Assignment";
 pub const WORK_VERIFICATION: &str = "Verify";
 pub const REVIEW: &str = "Review";
+pub const DISPUTE: &str = "Dispute";
+pub const MILESTONE: &str = "Milestone";
+pub const BONUS: &str = "Bonus";
+pub const TREASURY: &str = "Treasury";
+pub const VAULT: &str = "Vault";
+pub const NATIVE_TREASURY: &str = "NativeTreasury";
+pub const STREAM: &str = "Stream";
+pub const SKILL: &str = "Skill";
+pub const SKILL_PROFILE: &str = "Skills";
+pub const ENDORSEMENT: &str = "Endorsement";
+pub const CERTIFICATION: &str = "Certification";
+pub const CERTIFICATION_TYPE: &str = "CertificationType";
 
 // Limits
 pub const MAX_ADMINS: usize = 10;
+pub const MAX_ACCEPTED_MINTS: usize = 10;
 pub const MAX_NAME_LENGTH: usize = 100;
 pub const MAX_SKILLS: usize = 20;
+pub const MAX_SKILL_NAME_LENGTH: usize = 50;
+pub const MAX_CERTIFICATION_NAME_LENGTH: usize = 50;
+pub const MAX_REQUIRED_CERTIFICATIONS: usize = 5;
+pub const MAX_REASON_LENGTH: usize = 200;
+pub const MAX_URI_LENGTH: usize = 250;
+
+// Fees
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+// Reputation, averages are stored with two decimals (4.80 is 480)
+pub const RATING_SCALE: u32 = 100;
+// Weight of a fresh review, large enough that it only rounds to zero after about 60 half-lives
+pub const DECAY_WEIGHT_SCALE: u128 = 1_000_000_000_000_000_000;
+pub const DEFAULT_REPUTATION_HALF_LIFE: i64 = 180 * 24 * 60 * 60;
 
 // Misc
+pub const HOURS_PER_DAY: u8 = 8;
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+pub const DEFAULT_APPROVAL_WINDOW: i64 = 3 * 24 * 60 * 60;
 pub const MAX_METADATA_URL_LENGTH: usize = 200;
+
+// Each batched work day is passed as (assignment, work_verification, labour destination)
+pub const ACCOUNTS_PER_WORK_DAY: usize = 3;
@@ -88,4 +88,211 @@ pub enum ErrorCode {
 
     #[msg("The mint account does not match the system's configured mint.")]
     MintMismatch,
+
+    #[msg("Reason is too long")]
+    ReasonTooLong,
+
+    #[msg("Work day has not been verified by labour")]
+    WorkNotVerified,
+
+    #[msg("Payment already processed")]
+    PaymentAlreadyProcessed,
+
+    #[msg("Work day was rejected")]
+    WorkDayRejected,
+
+    #[msg("Work day is under dispute")]
+    DisputeOpen,
+
+    #[msg("Dispute is not open")]
+    DisputeNotOpen,
+
+    #[msg("Project has no labourers")]
+    NoLabourers,
+
+    #[msg("Project still has unsettled work days")]
+    UnsettledWorkDays,
+
+    #[msg("Invalid work verification account")]
+    InvalidWorkVerification,
+
+    #[msg("Instruction does not match the project payment mode")]
+    WrongPaymentMode,
+
+    #[msg("Invalid milestone amount")]
+    InvalidMilestoneAmount,
+
+    #[msg("Milestone is not assigned to this labour")]
+    MilestoneNotAssigned,
+
+    #[msg("Milestone has not been submitted")]
+    MilestoneNotSubmitted,
+
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+
+    #[msg("Work day has not been logged by the manager")]
+    WorkNotLogged,
+
+    #[msg("Invalid hours worked")]
+    InvalidHoursWorked,
+
+    #[msg("Hours worked do not match the verification")]
+    HoursMismatch,
+
+    #[msg("Invalid bonus amount")]
+    InvalidBonusAmount,
+
+    #[msg("Escrow surplus is too small for this payment")]
+    InsufficientEscrowSurplus,
+
+    #[msg("Fee is above the allowed maximum")]
+    InvalidFee,
+
+    #[msg("Mint is not accepted for payments")]
+    MintNotAccepted,
+
+    #[msg("Mint is already accepted")]
+    MintAlreadyAccepted,
+
+    #[msg("Accepted mint limit reached")]
+    MintLimitReached,
+
+    #[msg("Invalid approval window")]
+    InvalidApprovalWindow,
+
+    #[msg("Approval window has not elapsed yet")]
+    ApprovalWindowNotElapsed,
+
+    #[msg("Project escrow does not hold this asset")]
+    WrongEscrowAsset,
+
+    #[msg("Assignment is paid through a wage stream")]
+    AssignmentStreaming,
+
+    #[msg("Stream is paused")]
+    StreamPaused,
+
+    #[msg("Stream is not paused")]
+    StreamNotPaused,
+
+    #[msg("Stream has been stopped")]
+    StreamStopped,
+
+    #[msg("Nothing to withdraw")]
+    NothingToWithdraw,
+
+    #[msg("Work day is neither paid nor rejected")]
+    WorkDayNotSettled,
+
+    #[msg("Application is still pending")]
+    ApplicationStillPending,
+
+    #[msg("Assignment is still active")]
+    AssignmentStillActive,
+
+    #[msg("Stream still holds unpaid wages")]
+    StreamNotSettled,
+
+    #[msg("Wage streams must be stopped first")]
+    StreamsStillRunning,
+
+    #[msg("Users have not worked together on this project")]
+    NoWorkingRelationship,
+
+    #[msg("Review already has a response")]
+    ReviewAlreadyAnswered,
+
+    #[msg("Review is already hidden")]
+    ReviewHidden,
+
+    #[msg("Account is not the reviewee of this review")]
+    InvalidReviewee,
+
+    #[msg("Invalid reputation half-life")]
+    InvalidHalfLife,
+
+    #[msg("Skill name is too long")]
+    SkillNameTooLong,
+
+    #[msg("Skill has been retired")]
+    SkillRetired,
+
+    #[msg("Skill is already on the profile")]
+    SkillAlreadyAdded,
+
+    #[msg("Skill limit reached")]
+    SkillLimitReached,
+
+    #[msg("Skill is not on the profile")]
+    SkillNotOnProfile,
+
+    #[msg("Certification expiry must be in the future")]
+    InvalidCertificationExpiry,
+
+    #[msg("Certification has been revoked")]
+    CertificationRevoked,
+
+    #[msg("Too many required certifications")]
+    TooManyCertifications,
+
+    #[msg("Labour is missing a required certification")]
+    MissingCertification,
+
+    #[msg("Treasury account is required when a protocol fee is charged")]
+    TreasuryRequired,
+
+    #[msg("The stream account must be passed for a streaming assignment")]
+    StreamRequired,
+
+    #[msg("An accepted application is kept until its assignment exists or the project ends")]
+    ApplicationAccepted,
+
+    #[msg("Project is already completed or cancelled")]
+    ProjectAlreadyClosed,
+
+    #[msg("Cancellation fee was already paid for this assignment")]
+    CancellationFeeAlreadyPaid,
+
+    #[msg("Project was cancelled without a cancellation fee")]
+    NoCancellationFee,
+
+    #[msg("Response URI must be between 1 and 250 characters")]
+    InvalidResponseUri,
+
+    #[msg("Evidence URI must be at most 250 characters")]
+    EvidenceUriTooLong,
+
+    #[msg("Certification type name is too long")]
+    CertificationNameTooLong,
+
+    #[msg("Certification type has been retired")]
+    CertificationTypeRetired,
+
+    #[msg("Certification type does not match the registry")]
+    InvalidCertificationType,
+
+    #[msg("Cancellation fee has not been paid to this labour")]
+    CancellationFeeNotPaid,
+
+    #[msg("Application was closed before the assignment while the project is running")]
+    ApplicationAlreadyClosed,
+
+    #[msg("Dispute has not been resolved")]
+    DisputeNotResolved,
+
+    #[msg("Work metadata URI must be at most 250 characters")]
+    WorkMetadataUriTooLong,
+
+    #[msg("Submitted milestones must be approved or rejected first")]
+    UnsettledMilestones,
+
+    #[msg("Memo URI must be at most 250 characters")]
+    MemoUriTooLong,
+
+    #[msg("Milestone URI must be at most 250 characters")]
+    MilestoneUriTooLong,
+
+    #[msg("Cancellation fees were already reserved for this project")]
+    CancellationFeesAlreadyReserved,
 }
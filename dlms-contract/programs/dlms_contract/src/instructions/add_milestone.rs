@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Milestone, PaymentMode, Project, UserAccount};
+use crate::utils::{deposit_to_escrow, fund_milestone};
+
+#[derive(Accounts)]
+pub struct AddMilestone<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.payment_mode == PaymentMode::Milestone @ ErrorCode::WrongPaymentMode
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Milestone::INIT_SPACE,
+        seeds = [MILESTONE.as_bytes(), project.key().as_ref(), &[project.milestone_count]],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = manager_token_account.owner == authority.key() @ ErrorCode::WrongOwner,
+        constraint = manager_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub manager_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_milestone(
+    ctx: Context<AddMilestone>,
+    amount: u64,
+    description_uri: String,
+) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    fund_milestone(
+        &mut accounts.milestone,
+        &mut accounts.project,
+        amount,
+        description_uri,
+        |deposit_amount| {
+            deposit_to_escrow(
+                &accounts.token_program,
+                &accounts.manager_token_account,
+                &mut accounts.escrow_account,
+                &accounts.mint,
+                &accounts.authority,
+                deposit_amount,
+            )?;
+
+            Ok(())
+        },
+    )
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, Milestone, NativeEscrow, PaymentMode, Project, UserAccount};
+use crate::utils::fund_milestone;
+
+#[derive(Accounts)]
+pub struct AddMilestoneNative<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.payment_mode == PaymentMode::Milestone @ ErrorCode::WrongPaymentMode,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Milestone::INIT_SPACE,
+        seeds = [MILESTONE.as_bytes(), project.key().as_ref(), &[project.milestone_count]],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_milestone_native(
+    ctx: Context<AddMilestoneNative>,
+    amount: u64,
+    description_uri: String,
+) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    fund_milestone(
+        &mut accounts.milestone,
+        &mut accounts.project,
+        amount,
+        description_uri,
+        |deposit_amount| {
+            require!(
+                accounts.authority.lamports() >= deposit_amount,
+                ErrorCode::InsufficientFunds
+            );
+
+            let cpi_ctx = CpiContext::new(
+                accounts.system_program.to_account_info(),
+                Transfer {
+                    from: accounts.authority.to_account_info(),
+                    to: accounts.escrow_account.to_account_info(),
+                },
+            );
+            system_program::transfer(cpi_ctx, deposit_amount)
+        },
+    )
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Project, UserAccount};
+use crate::utils::deposit_to_escrow;
+
+#[derive(Accounts)]
+pub struct AmendProject<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = manager_token_account.owner == authority.key() @ ErrorCode::WrongOwner,
+        constraint = manager_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub manager_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn amend_project(
+    ctx: Context<AmendProject>,
+    deposit_amount: u64,
+    duration_days: Option<u16>,
+    daily_rate: Option<u64>,
+) -> Result<()> {
+    ctx.accounts.project.amend(duration_days, daily_rate)?;
+
+    if deposit_amount > 0 {
+        deposit_to_escrow(
+            &ctx.accounts.token_program,
+            &ctx.accounts.manager_token_account,
+            &mut ctx.accounts.escrow_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.authority,
+            deposit_amount,
+        )?;
+
+        msg!("Deposited {} into escrow", deposit_amount);
+    }
+
+    ctx.accounts.project.require_funded(ctx.accounts.escrow_account.amount)
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, NativeEscrow, Project, UserAccount};
+
+#[derive(Accounts)]
+pub struct AmendProjectNative<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn amend_project_native(
+    ctx: Context<AmendProjectNative>,
+    deposit_amount: u64,
+    duration_days: Option<u16>,
+    daily_rate: Option<u64>,
+) -> Result<()> {
+    ctx.accounts.project.amend(duration_days, daily_rate)?;
+
+    if deposit_amount > 0 {
+        require!(
+            ctx.accounts.authority.lamports() >= deposit_amount,
+            ErrorCode::InsufficientFunds
+        );
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.escrow_account.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, deposit_amount)?;
+
+        msg!("Deposited {} into escrow", deposit_amount);
+    }
+
+    // Lamports above the vault's rent exempt balance are what it holds for wages
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let escrow_balance = escrow_info
+        .lamports()
+        .checked_sub(rent_exempt)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+
+    ctx.accounts.project.require_funded(escrow_balance)
+}
@@ -2,6 +2,7 @@
 use crate::constants::*;
 use crate::error::ErrorCode;
 use crate::states::{Project, ProjectStatus, UserAccount, Application, ApplicationStatus};
+use crate::utils::require_certifications;
 
 
  #[derive(Accounts)]
@@ -49,13 +50,22 @@ pub struct ApplyToProject<'info> {
             ErrorCode::ProjectFull
         );
         
+        // Certifications required by the project are passed in remaining accounts
+        let now = Clock::get()?.unix_timestamp;
+        require_certifications(
+            &ctx.accounts.project.required_certifications,
+            ctx.accounts.labour_account.key(),
+            ctx.remaining_accounts,
+            now,
+        )?;
+        
         let application = &mut ctx.accounts.application;
         
         application.labour = ctx.accounts.labour_account.key();
         application.project = ctx.accounts.project.key();
         application.description = description;
         application.status = ApplicationStatus::Pending;
-        application.timestamp = Clock::get()?.unix_timestamp;
+        application.timestamp = now;
         
         Ok(())
     }
\ No newline at end of file
@@ -2,6 +2,7 @@
 use crate::constants::*;
 use crate::error::ErrorCode;
 use crate::states::{Project, ProjectStatus, UserAccount, UserRole, Application, Assignment, ApplicationStatus};
+use crate::utils::{escrow_balance, require_certifications};
 
 #[derive(Accounts)]
 pub struct ApproveApplication<'info> {
@@ -27,6 +28,12 @@ pub struct ApproveApplication<'info> {
     )]
     pub project: Account<'info, Project>,
     
+    /// CHECK: The project's token or native escrow, only its balance is read
+    #[account(
+        address = project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    
     #[account(
         seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
         bump ,
@@ -62,10 +69,18 @@ pub fn approve_application(
             ErrorCode::ProjectFull
         );
         
+        // Checked again on approval, a certification may have expired or been revoked since applying
+        require_certifications(
+            &ctx.accounts.project.required_certifications,
+            ctx.accounts.labour_account.key(),
+            ctx.remaining_accounts,
+            Clock::get()?.unix_timestamp,
+        )?;
+        
         let application = &mut ctx.accounts.application;
         let project = &mut ctx.accounts.project;
         
-        application.status = ApplicationStatus::Pending;
+        application.status = ApplicationStatus::Accepted;
         application.timestamp = Clock::get()?.unix_timestamp;
         
         // Create labour's assignment to the project
@@ -76,12 +91,27 @@ pub fn approve_application(
         assignment.days_paid = 0;
         assignment.active = true;
         assignment.timestamp = Clock::get()?.unix_timestamp;
+        assignment.ended_at = 0;
+        assignment.termination_reason = None;
+        assignment.resigned = false;
+        assignment.bonus_count = 0;
+        assignment.bonus_total = 0;
+        assignment.streaming = false;
+        assignment.cancellation_fee_paid = false;
+        assignment.days_claimed = 0;
         
         // Update project labour count
-        project.labour_count += 1;
+        project.labour_count = project
+            .labour_count
+            .checked_add(1)
+            .ok_or(ErrorCode::CalculationError)?;
+        project.add_daily_labourer().ok_or(ErrorCode::CalculationError)?;
         if project.labour_count == project.max_labourers {
             project.status = ProjectStatus::InProgress;
         }
         
-        Ok(())
+        // A reopened slot is owed in full to the new hire, but the days paid to whoever held it
+        // before have already left the escrow, so the manager may need to top it up first
+        let escrow_balance = escrow_balance(project, &ctx.accounts.escrow_account)?;
+        project.require_funded(escrow_balance)
     }
\ No newline at end of file
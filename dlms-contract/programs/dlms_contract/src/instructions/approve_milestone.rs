@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Milestone, Project, UserAccount};
+use crate::utils::{pay_wage, settle_milestone};
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE.as_bytes(), project.key().as_ref(), &[milestone.index]],
+        bump,
+        constraint = milestone.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = milestone.assignee == Some(labour_account.key()) @ ErrorCode::MilestoneNotAssigned
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = labour_token_account.owner == labour_account.authority @ ErrorCode::WrongOwner,
+        constraint = labour_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub labour_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY.as_bytes(), project.mint.as_ref()],
+        bump
+    )]
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn approve_milestone(ctx: Context<ApproveMilestone>) -> Result<()> {
+    let project_bump = ctx.bumps.project;
+    let accounts = &mut *ctx.accounts;
+    settle_milestone(
+        &mut accounts.milestone,
+        &mut accounts.project,
+        |project, amount| {
+            pay_wage(
+                &accounts.token_program,
+                &accounts.escrow_account,
+                &accounts.labour_token_account,
+                accounts.treasury_account.as_ref(),
+                &accounts.mint,
+                project,
+                project_bump,
+                amount,
+            )
+        },
+    )
+}
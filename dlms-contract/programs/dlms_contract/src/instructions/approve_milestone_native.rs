@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, Milestone, NativeEscrow, NativeTreasury, Project, UserAccount};
+use crate::utils::{pay_native_wage, settle_milestone};
+
+#[derive(Accounts)]
+pub struct ApproveMilestoneNative<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE.as_bytes(), project.key().as_ref(), &[milestone.index]],
+        bump,
+        constraint = milestone.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = milestone.assignee == Some(labour_account.key()) @ ErrorCode::MilestoneNotAssigned
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        mut,
+        address = labour_account.authority @ ErrorCode::WrongOwner
+    )]
+    pub labour_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Option<Account<'info, NativeTreasury>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn approve_milestone_native(ctx: Context<ApproveMilestoneNative>) -> Result<()> {
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    let labour_wallet_info = ctx.accounts.labour_wallet.to_account_info();
+    let treasury_info = ctx.accounts.treasury_account.as_ref().map(|treasury| treasury.to_account_info());
+    let accounts = &mut *ctx.accounts;
+    settle_milestone(
+        &mut accounts.milestone,
+        &mut accounts.project,
+        |project, amount| {
+            pay_native_wage(&escrow_info, &labour_wallet_info, treasury_info.as_ref(), project, amount)
+        },
+    )
+}
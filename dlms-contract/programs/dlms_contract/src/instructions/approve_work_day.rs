@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::ErrorCode;
 use crate::states::{Assignment, Project, UserAccount,
     WorkVerification,
 };
+use crate::utils::{pay_wage, settle_approved_work_day};
 
 #[derive(Accounts)]
 pub struct ApproveWorkDay<'info> {
@@ -52,83 +53,58 @@ pub struct ApproveWorkDay<'info> {
         bump,
         constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
     )]
-    pub escrow_account: Account<'info, TokenAccount>,
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = labour_token_account.owner == labour_account.authority @ ErrorCode::WrongOwner,
+        constraint = labour_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub labour_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = labour_token_account.owner == labour_account.authority @ ErrorCode::WrongOwner
+        seeds = [TREASURY.as_bytes(), project.mint.as_ref()],
+        bump
     )]
-    pub labour_token_account: Account<'info, TokenAccount>,
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn approve_work_day(ctx: Context<ApproveWorkDay>) -> Result<()> {
-    require!(
-        !ctx.accounts.work_verification.manager_verified,
-        ErrorCode::AlreadyVerified
-    );
-
-    // Verify the work
-    let work_verification = &mut ctx.accounts.work_verification;
-    work_verification.manager_verified = true;
-    work_verification.timestamp = Clock::get()?.unix_timestamp;
-
-    require!(
-        work_verification.day_number == ctx.accounts.assignment.days_worked + 1,
-        ErrorCode::InvalidDaySequence
-    );
-
-    // Process payment if both parties have verified
-    if work_verification.labour_verified && work_verification.manager_verified {
-        // Calculate tokens to release from escrow
-        let payment_amount = ctx.accounts.project.daily_rate;
-
-        let manager_key = ctx.accounts.manager_account.key();
-        let project_index_bytes = ctx.accounts.project.index.to_le_bytes();
-
-        msg!("Project address: {}", ctx.accounts.project.key());
-        msg!("Escrow address: {}", ctx.accounts.escrow_account.key());
-        msg!("Escrow owner: {}", ctx.accounts.escrow_account.owner);
-        msg!("Payment amount: {}", payment_amount);
-
-        // Make sure we're using the correct seeds and bump for the project PDA
-        let project_seeds = &[
-            PROJECT_SEED.as_bytes(),
-            manager_key.as_ref(),
-            &ctx.accounts.project.index.to_le_bytes(),
-            &[ctx.bumps.project],
-        ];
-
-        // Create the signer seeds array
-        let signer = &[&project_seeds[..]];
-
-        // Transfer payment from escrow to labour
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.escrow_account.to_account_info(),
-                to: ctx.accounts.labour_token_account.to_account_info(),
-                authority: ctx.accounts.project.to_account_info(), // Project is the authority
-            },
-            signer,
-        );
-
-        // Execute the transfer
-        token::transfer(transfer_ctx, payment_amount)?;
-
-        // Update verification record
-        work_verification.payment_processed = true;
-
-        // Update assignment
-        ctx.accounts.assignment.days_paid += 1;
-
-        ctx.accounts.assignment.days_worked += 1;
-    }
-
-    Ok(())
+pub fn approve_work_day(ctx: Context<ApproveWorkDay>, hours_worked: u8) -> Result<()> {
+    msg!("Project address: {}", ctx.accounts.project.key());
+    msg!("Escrow address: {}", ctx.accounts.escrow_account.key());
+    msg!("Escrow owner: {}", ctx.accounts.escrow_account.owner);
+
+    // Transfer payment from escrow to labour, the project PDA signs as escrow authority
+    let project_bump = ctx.bumps.project;
+    let accounts = &mut *ctx.accounts;
+    settle_approved_work_day(
+        &mut accounts.work_verification,
+        &mut accounts.assignment,
+        &mut accounts.project,
+        hours_worked,
+        |project, wage| {
+            pay_wage(
+                &accounts.token_program,
+                &accounts.escrow_account,
+                &accounts.labour_token_account,
+                accounts.treasury_account.as_ref(),
+                &accounts.mint,
+                project,
+                project_bump,
+                wage,
+            )
+        },
+    )
 }
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Project, UserAccount};
+use crate::utils::{pay_wage, settle_approved_work_days};
+
+#[derive(Accounts)]
+pub struct ApproveWorkDaysBatch<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY.as_bytes(), project.mint.as_ref()],
+        bump
+    )]
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn approve_work_days_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApproveWorkDaysBatch<'info>>,
+    hours_worked: Vec<u8>,
+) -> Result<()> {
+    // Each approved day is passed as (assignment, work_verification, labour_token_account)
+    let project_bump = ctx.bumps.project;
+    let accounts = &mut *ctx.accounts;
+    settle_approved_work_days(
+        ctx.remaining_accounts,
+        &mut accounts.project,
+        hours_worked,
+        |project, destination, wage| {
+            let labour_token_account = InterfaceAccount::<TokenAccount>::try_from(destination)?;
+            require_keys_eq!(labour_token_account.mint, project.mint, ErrorCode::MintMismatch);
+
+            pay_wage(
+                &accounts.token_program,
+                &accounts.escrow_account,
+                &labour_token_account,
+                accounts.treasury_account.as_ref(),
+                &accounts.mint,
+                project,
+                project_bump,
+                wage,
+            )?;
+
+            Ok(labour_token_account.owner)
+        },
+    )
+}
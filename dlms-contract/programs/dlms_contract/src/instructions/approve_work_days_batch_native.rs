@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, NativeEscrow, NativeTreasury, Project, UserAccount};
+use crate::utils::{pay_native_wage, settle_approved_work_days};
+
+#[derive(Accounts)]
+pub struct ApproveWorkDaysBatchNative<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        mut,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Option<Account<'info, NativeTreasury>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn approve_work_days_batch_native<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApproveWorkDaysBatchNative<'info>>,
+    hours_worked: Vec<u8>,
+) -> Result<()> {
+    // Each approved day is passed as (assignment, work_verification, labour_wallet)
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    let treasury_info = ctx.accounts.treasury_account.as_ref().map(|treasury| treasury.to_account_info());
+    settle_approved_work_days(
+        ctx.remaining_accounts,
+        &mut ctx.accounts.project,
+        hours_worked,
+        |project, labour_wallet, wage| {
+            pay_native_wage(&escrow_info, labour_wallet, treasury_info.as_ref(), project, wage)?;
+            Ok(*labour_wallet.key)
+        },
+    )
+}
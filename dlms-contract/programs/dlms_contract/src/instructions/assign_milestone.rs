@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Milestone, Project, UserAccount};
+
+#[derive(Accounts)]
+pub struct AssignMilestone<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE.as_bytes(), project.key().as_ref(), &[milestone.index]],
+        bump,
+        constraint = milestone.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = !milestone.labour_verified @ ErrorCode::AlreadyVerified
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        seeds = [ASSIGNMENT.as_bytes(), assignment.labour.as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn assign_milestone(ctx: Context<AssignMilestone>) -> Result<()> {
+    ctx.accounts.milestone.assignee = Some(ctx.accounts.assignment.labour);
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Project, SystemState, UserAccount, WorkVerification};
+use crate::utils::{pay_wage, settle_claimed_work_day};
+
+#[derive(Accounts)]
+pub struct ClaimWorkDay<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == work_verification.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.labour == labour_account.key() @ ErrorCode::InvalidLabour,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        mut,
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump,
+        constraint = work_verification.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = work_verification.labour == labour_account.key() @ ErrorCode::InvalidLabour
+    )]
+    pub work_verification: Account<'info, WorkVerification>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = labour_token_account.owner == labour_account.authority @ ErrorCode::WrongOwner,
+        constraint = labour_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub labour_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY.as_bytes(), project.mint.as_ref()],
+        bump
+    )]
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // Anyone may crank the claim once the window has passed
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn claim_work_day(ctx: Context<ClaimWorkDay>) -> Result<()> {
+    // Still claimable after the project is closed, close_project holds the funds back
+    let project_bump = ctx.bumps.project;
+    let accounts = &mut *ctx.accounts;
+    settle_claimed_work_day(
+        &mut accounts.work_verification,
+        &mut accounts.assignment,
+        &mut accounts.project,
+        accounts.system_state.approval_window,
+        |project, wage| {
+            pay_wage(
+                &accounts.token_program,
+                &accounts.escrow_account,
+                &accounts.labour_token_account,
+                accounts.treasury_account.as_ref(),
+                &accounts.mint,
+                project,
+                project_bump,
+                wage,
+            )
+        },
+    )
+}
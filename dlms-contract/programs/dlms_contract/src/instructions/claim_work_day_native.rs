@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, EscrowAsset, NativeEscrow, NativeTreasury, Project, SystemState, UserAccount, WorkVerification};
+use crate::utils::{pay_native_wage, settle_claimed_work_day};
+
+#[derive(Accounts)]
+pub struct ClaimWorkDayNative<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == work_verification.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.labour == labour_account.key() @ ErrorCode::InvalidLabour,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        mut,
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump,
+        constraint = work_verification.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = work_verification.labour == labour_account.key() @ ErrorCode::InvalidLabour
+    )]
+    pub work_verification: Account<'info, WorkVerification>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        mut,
+        address = labour_account.authority @ ErrorCode::WrongOwner
+    )]
+    pub labour_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Option<Account<'info, NativeTreasury>>,
+
+    // Anyone may crank the claim once the window has passed
+    pub authority: Signer<'info>,
+}
+
+pub fn claim_work_day_native(ctx: Context<ClaimWorkDayNative>) -> Result<()> {
+    // Still claimable after the project is closed, close_native_project holds the lamports back
+    let accounts = &mut *ctx.accounts;
+    settle_claimed_work_day(
+        &mut accounts.work_verification,
+        &mut accounts.assignment,
+        &mut accounts.project,
+        accounts.system_state.approval_window,
+        |project, wage| {
+            pay_native_wage(
+                &accounts.escrow_account.to_account_info(),
+                &accounts.labour_wallet.to_account_info(),
+                accounts.treasury_account.as_ref().map(AsRef::as_ref),
+                project,
+                wage,
+            )
+        },
+    )
+}
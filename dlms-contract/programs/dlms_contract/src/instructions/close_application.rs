@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Application, ApplicationStatus, Project, UserAccount};
+
+#[derive(Accounts)]
+pub struct CloseApplication<'info> {
+    #[account(
+        mut,
+        close = labour_authority,
+        seeds = [APPLICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = application.labour == labour_account.key() @ ErrorCode::InvalidLabour
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        address = application.project @ ErrorCode::InvalidProject
+    )]
+    pub project: Account<'info, Project>,
+
+    pub labour_account: Account<'info, UserAccount>,
+
+    /// CHECK: Assignment the application was accepted into, it may not exist
+    #[account(
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump
+    )]
+    pub assignment: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the rent, the labour paid for the application
+    #[account(
+        mut,
+        address = labour_account.authority @ ErrorCode::NotAuthorized
+    )]
+    pub labour_authority: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn close_application(ctx: Context<CloseApplication>) -> Result<()> {
+    // Nobody can apply or be hired once the project has ended
+    if ctx.accounts.project.is_finished() {
+        return Ok(());
+    }
+
+    // Rejected and withdrawn applications are closed right away, only these two remain
+    match ctx.accounts.application.status {
+        ApplicationStatus::Pending => err!(ErrorCode::ApplicationStillPending),
+        // The assignment keeps the labour from being hired twice while it exists
+        ApplicationStatus::Accepted => {
+            require!(
+                !ctx.accounts.assignment.data_is_empty(),
+                ErrorCode::ApplicationAccepted
+            );
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{assignment, daily_rate_project, user_account};
+    use crate::states::{ProjectStatus, UserRole};
+    use crate::utils::tests::{pda, process, program_account_at, signer, system_account};
+
+    // Accounts of a close, in instruction order, for an application in `status` on a
+    // project in `project_status`, with the labour still assigned when `assigned`
+    fn closing(
+        status: ApplicationStatus,
+        project_status: ProjectStatus,
+        assigned: bool,
+    ) -> [&'static AccountInfo<'static>; 6] {
+        let labour = Pubkey::new_unique();
+        let labour_key = pda(&[USER_STATE.as_bytes(), labour.as_ref()]);
+
+        let mut project = daily_rate_project();
+        project.status = project_status;
+        let project_key = Pubkey::new_unique();
+
+        let application = Application {
+            labour: labour_key,
+            project: project_key,
+            description: String::new(),
+            status,
+            timestamp: 0,
+        };
+        let assignment_key = pda(&[ASSIGNMENT.as_bytes(), labour_key.as_ref(), project_key.as_ref()]);
+
+        [
+            program_account_at(
+                pda(&[APPLICATION.as_bytes(), labour_key.as_ref(), project_key.as_ref()]),
+                &application,
+            ),
+            program_account_at(project_key, &project),
+            program_account_at(labour_key, &user_account(labour, UserRole::Labour)),
+            if assigned {
+                program_account_at(assignment_key, &assignment(0))
+            } else {
+                system_account(assignment_key)
+            },
+            system_account(labour),
+            signer(Pubkey::new_unique()),
+        ]
+    }
+
+    #[test]
+    fn accepted_application_is_closed_while_its_assignment_exists() {
+        let accounts = closing(ApplicationStatus::Accepted, ProjectStatus::InProgress, true);
+        let [application, _, _, _, labour_authority, _] = accounts;
+        let rent = application.lamports();
+
+        process::<CloseApplication>(&accounts, close_application).unwrap();
+
+        assert_eq!(labour_authority.lamports(), rent);
+        assert!(application.data_is_empty());
+    }
+
+    #[test]
+    fn open_application_stays_on_a_running_project() {
+        let accounts = closing(ApplicationStatus::Pending, ProjectStatus::InProgress, false);
+        let result = process::<CloseApplication>(&accounts, close_application);
+        assert_eq!(result.unwrap_err(), ErrorCode::ApplicationStillPending.into());
+
+        // With the assignment closed the application is all that keeps the labour from
+        // being hired again
+        let accounts = closing(ApplicationStatus::Accepted, ProjectStatus::InProgress, false);
+        let result = process::<CloseApplication>(&accounts, close_application);
+        assert_eq!(result.unwrap_err(), ErrorCode::ApplicationAccepted.into());
+    }
+
+    #[test]
+    fn any_application_of_a_finished_project_is_closed() {
+        let accounts = closing(ApplicationStatus::Pending, ProjectStatus::Cancelled, false);
+        process::<CloseApplication>(&accounts, close_application).unwrap();
+        assert!(accounts[0].data_is_empty());
+
+        let accounts = closing(ApplicationStatus::Accepted, ProjectStatus::Completed, false);
+        process::<CloseApplication>(&accounts, close_application).unwrap();
+        assert!(accounts[0].data_is_empty());
+    }
+}
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Project, ProjectStatus, Stream, UserAccount};
+
+#[derive(Accounts)]
+pub struct CloseAssignment<'info> {
+    #[account(
+        constraint = manager_account.key() == project.manager @ ErrorCode::InvalidManager
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == assignment.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        close = manager_authority,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    /// CHECK: Application the labour was hired from, it must still exist on a running project
+    #[account(
+        seeds = [APPLICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump
+    )]
+    pub application: UncheckedAccount<'info>,
+
+    /// CHECK: Verification of the next unpaid day, it must not exist
+    #[account(
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump
+    )]
+    pub work_verification: UncheckedAccount<'info>,
+
+    // Only passed for streaming assignments, it is closed along with the assignment
+    #[account(
+        mut,
+        close = manager_authority,
+        seeds = [STREAM.as_bytes(), assignment.key().as_ref()],
+        bump
+    )]
+    pub stream: Option<Account<'info, Stream>>,
+
+    /// CHECK: Receives the rent, the manager paid for the assignment and its stream
+    #[account(
+        mut,
+        address = manager_account.authority @ ErrorCode::NotAuthorized
+    )]
+    pub manager_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = authority.key() == manager_account.authority ||
+            authority.key() == labour_account.authority @ ErrorCode::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn close_assignment(ctx: Context<CloseAssignment>) -> Result<()> {
+    let project = &ctx.accounts.project;
+    let assignment = &ctx.accounts.assignment;
+
+    if project.is_finished() {
+        // Labour still owed a cancellation fee must be paid through pay_cancellation_fees first
+        require!(
+            !assignment.active ||
+            project.status != ProjectStatus::Cancelled ||
+            project.cancellation_fee == 0 ||
+            assignment.cancellation_fee_paid,
+            ErrorCode::CancellationFeeNotPaid
+        );
+    } else {
+        require!(!assignment.active, ErrorCode::AssignmentStillActive);
+
+        // Without the application the labour could apply again and be hired into a fresh
+        // assignment whose day numbers collide with the old verifications
+        require!(
+            !ctx.accounts.application.data_is_empty(),
+            ErrorCode::ApplicationAlreadyClosed
+        );
+    }
+
+    // A verification for the next day means it is still awaiting payment or a ruling
+    require!(
+        ctx.accounts.work_verification.data_is_empty(),
+        ErrorCode::UnsettledWorkDays
+    );
+
+    if ctx.accounts.assignment.streaming {
+        let stream = ctx
+            .accounts
+            .stream
+            .as_ref()
+            .ok_or(ErrorCode::StreamNotSettled)?;
+
+        require!(
+            stream.stopped && stream.withdrawn == stream.accrued,
+            ErrorCode::StreamNotSettled
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{assignment, daily_rate_project, user_account};
+    use crate::states::{Application, ApplicationStatus, UserRole};
+    use crate::utils::tests::{
+        pda, process, program_account_at, signer, system_account, work_verification,
+    };
+
+    // Accounts of a close, in instruction order. The application is kept when `applied` and
+    // the next day of the assignment awaits payment when `unpaid_day`
+    fn closing(
+        project_status: ProjectStatus,
+        active: bool,
+        applied: bool,
+        unpaid_day: bool,
+    ) -> [&'static AccountInfo<'static>; 9] {
+        let manager = Pubkey::new_unique();
+        let labour = Pubkey::new_unique();
+        let manager_key = pda(&[USER_STATE.as_bytes(), manager.as_ref()]);
+        let labour_key = pda(&[USER_STATE.as_bytes(), labour.as_ref()]);
+
+        let mut project = daily_rate_project();
+        project.manager = manager_key;
+        project.status = project_status;
+        project.cancellation_fee = 100;
+        let project_key = pda(&[PROJECT_SEED.as_bytes(), manager_key.as_ref(), &project.index.to_le_bytes()]);
+
+        let mut assignment = assignment(2);
+        assignment.labour = labour_key;
+        assignment.project = project_key;
+        assignment.active = active;
+
+        let application_key = pda(&[APPLICATION.as_bytes(), labour_key.as_ref(), project_key.as_ref()]);
+        let application = Application {
+            labour: labour_key,
+            project: project_key,
+            description: String::new(),
+            status: ApplicationStatus::Accepted,
+            timestamp: 0,
+        };
+        let work_verification_key = pda(&[
+            WORK_VERIFICATION.as_bytes(),
+            labour_key.as_ref(),
+            project_key.as_ref(),
+            &3u16.to_le_bytes(),
+        ]);
+
+        [
+            program_account_at(manager_key, &user_account(manager, UserRole::Manager)),
+            program_account_at(project_key, &project),
+            program_account_at(labour_key, &user_account(labour, UserRole::Labour)),
+            program_account_at(
+                pda(&[ASSIGNMENT.as_bytes(), labour_key.as_ref(), project_key.as_ref()]),
+                &assignment,
+            ),
+            if applied {
+                program_account_at(application_key, &application)
+            } else {
+                system_account(application_key)
+            },
+            if unpaid_day {
+                program_account_at(work_verification_key, &work_verification(HOURS_PER_DAY))
+            } else {
+                system_account(work_verification_key)
+            },
+            // No stream, the program id stands in for an absent optional account
+            system_account(crate::ID),
+            system_account(manager),
+            signer(labour),
+        ]
+    }
+
+    #[test]
+    fn ended_assignment_is_closed_to_the_manager() {
+        let accounts = closing(ProjectStatus::InProgress, false, true, false);
+        let [_, _, _, assignment, _, _, _, manager_authority, _] = accounts;
+        let rent = assignment.lamports();
+
+        process::<CloseAssignment>(&accounts, close_assignment).unwrap();
+
+        assert_eq!(manager_authority.lamports(), rent);
+        assert!(assignment.data_is_empty());
+    }
+
+    #[test]
+    fn assignment_on_a_running_project_must_be_settled() {
+        let result = process::<CloseAssignment>(
+            &closing(ProjectStatus::InProgress, true, true, false),
+            close_assignment,
+        );
+        assert_eq!(result.unwrap_err(), ErrorCode::AssignmentStillActive.into());
+
+        let result = process::<CloseAssignment>(
+            &closing(ProjectStatus::InProgress, false, false, false),
+            close_assignment,
+        );
+        assert_eq!(result.unwrap_err(), ErrorCode::ApplicationAlreadyClosed.into());
+
+        let result = process::<CloseAssignment>(
+            &closing(ProjectStatus::InProgress, false, true, true),
+            close_assignment,
+        );
+        assert_eq!(result.unwrap_err(), ErrorCode::UnsettledWorkDays.into());
+    }
+
+    #[test]
+    fn cancelled_project_assignment_waits_for_its_cancellation_fee() {
+        let accounts = closing(ProjectStatus::Cancelled, true, false, false);
+        let result = process::<CloseAssignment>(&accounts, close_assignment);
+        assert_eq!(result.unwrap_err(), ErrorCode::CancellationFeeNotPaid.into());
+
+        let accounts = closing(ProjectStatus::Completed, true, false, false);
+        process::<CloseAssignment>(&accounts, close_assignment).unwrap();
+        assert!(accounts[3].data_is_empty());
+    }
+}
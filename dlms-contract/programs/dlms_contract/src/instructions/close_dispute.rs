@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Dispute, DisputeStatus};
+
+#[derive(Accounts)]
+pub struct CloseDispute<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [DISPUTE.as_bytes(), dispute.work_verification.as_ref()],
+        bump,
+        constraint = dispute.status == DisputeStatus::Resolved @ ErrorCode::DisputeNotResolved
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// CHECK: Receives the rent, must be whoever opened the dispute
+    #[account(
+        mut,
+        address = dispute.rent_payer @ ErrorCode::NotAuthorized
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = authority.key() == dispute.rent_payer @ ErrorCode::NotAuthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+// Only whoever paid for the dispute decides when its ruling no longer needs to stay on chain
+pub fn close_dispute(ctx: Context<CloseDispute>) -> Result<()> {
+    msg!(
+        "Closed dispute {}, rent returned to {}",
+        ctx.accounts.dispute.key(),
+        ctx.accounts.rent_payer.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, NativeEscrow, Project, ProjectStatus, UserAccount};
+use crate::utils::{reserve_cancellation_fees, settle_cancellation_fees, withdraw_lamports};
+
+#[derive(Accounts)]
+pub struct CloseNativeProject<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_native_project<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseNativeProject<'info>>,
+    status: ProjectStatus,
+    cancellation_fee: u64,
+) -> Result<()> {
+    // Can only close to completed or canceled, a closed project can still be closed again
+    // to refund what is no longer held back, e.g. once a held back day was rejected
+    ctx.accounts.project.finish(status)?;
+
+    // Labour verified days stay in the vault, they remain payable after the close
+    let holdback = ctx
+        .accounts
+        .project
+        .settlement_holdback()
+        .ok_or(ErrorCode::CalculationError)?;
+
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let mut refund_amount = escrow_info
+        .lamports()
+        .saturating_sub(rent_exempt)
+        .saturating_sub(holdback);
+
+    if cancellation_fee > 0 {
+        refund_amount = reserve_cancellation_fees(
+            &mut ctx.accounts.project,
+            refund_amount,
+            cancellation_fee,
+        )?;
+
+        // Assignments passed as (assignment, labour wallet) pairs are paid now,
+        // the rest stay in the vault until pay_cancellation_fees_native is called for them
+        settle_cancellation_fees(
+            ctx.remaining_accounts,
+            &mut ctx.accounts.project,
+            |project, labour_wallet| {
+                withdraw_lamports(&escrow_info, labour_wallet, project.cancellation_fee)?;
+                Ok(*labour_wallet.key)
+            },
+        )?;
+    }
+
+    // With nothing left to settle the vault is closed, refunding its rent as well
+    let holdback = ctx
+        .accounts
+        .project
+        .settlement_holdback()
+        .ok_or(ErrorCode::CalculationError)?;
+    if holdback == 0 {
+        msg!("Refunding {} lamports to manager", escrow_info.lamports());
+
+        // The project no longer has a vault, later native instructions on it fail on the escrow
+        ctx.accounts.project.escrow_account = Pubkey::default();
+        return ctx
+            .accounts
+            .escrow_account
+            .close(ctx.accounts.authority.to_account_info());
+    }
+
+    msg!("Refunding {} lamports to manager", refund_amount);
+    if refund_amount > 0 {
+        withdraw_lamports(
+            &escrow_info,
+            &ctx.accounts.authority.to_account_info(),
+            refund_amount,
+        )?;
+    }
+
+    Ok(())
+}
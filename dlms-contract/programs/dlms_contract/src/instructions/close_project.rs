@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::ErrorCode;
 use crate::states::{Project, ProjectStatus, UserAccount};
+use crate::utils::{reserve_cancellation_fees, settle_cancellation_fees, transfer_from_escrow};
 
 #[derive(Accounts)]
 pub struct CloseProject<'info> {
@@ -27,53 +28,97 @@ pub struct CloseProject<'info> {
         bump,
         constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
     )]
-    pub escrow_account: Account<'info, TokenAccount>,
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
-        constraint = manager_token_account.owner == manager_account.authority @ ErrorCode::WrongOwner
+        constraint = manager_token_account.owner == manager_account.authority @ ErrorCode::WrongOwner,
+        constraint = manager_token_account.mint == project.mint @ ErrorCode::MintMismatch
     )]
-    pub manager_token_account: Account<'info, TokenAccount>,
+    pub manager_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn close_project(ctx: Context<CloseProject>, status: ProjectStatus) -> Result<()> {
-    // Can only close completed or canceled projects
+pub fn close_project<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseProject<'info>>,
+    status: ProjectStatus,
+    cancellation_fee: u64,
+) -> Result<()> {
+    // Can only close to completed or canceled, a closed project can still be closed again
+    // to refund what is no longer held back, e.g. once a held back day was rejected
+    ctx.accounts.project.finish(status)?;
+
+    // Running streams keep accruing, they must be stopped so what they owe is known
     require!(
-        status == ProjectStatus::Completed || status == ProjectStatus::Cancelled,
-        ErrorCode::WrongProjectStatus
+        ctx.accounts.project.active_streams == 0,
+        ErrorCode::StreamsStillRunning
     );
 
-    // Return any remaining escrow to manager
-    if ctx.accounts.escrow_account.amount > 0 {
-        let bump = ctx.bumps.escrow_account;
-
-        let seeds = &[
-            ESCROW_SEED.as_bytes(),
-            ctx.accounts.project.to_account_info().key.as_ref(),
-            &[bump],
-        ];
-        let signer = &[&seeds[..]];
-
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.escrow_account.to_account_info(),
-                to: ctx.accounts.manager_token_account.to_account_info(),
-                authority: ctx.accounts.project.to_account_info(),
+    // Work claimed but not paid yet stays in escrow, it remains payable after the close
+    let holdback = ctx
+        .accounts
+        .project
+        .settlement_holdback()
+        .ok_or(ErrorCode::CalculationError)?;
+    let mut refund_amount = ctx.accounts.escrow_account.amount.saturating_sub(holdback);
+
+    msg!("Holding back {} for unsettled work", holdback);
+
+    if cancellation_fee > 0 {
+        refund_amount = reserve_cancellation_fees(
+            &mut ctx.accounts.project,
+            refund_amount,
+            cancellation_fee,
+        )?;
+
+        // Assignments passed as (assignment, labour token account) pairs are paid now,
+        // the rest stay in escrow until pay_cancellation_fees is called for them
+        let project_bump = ctx.bumps.project;
+        let accounts = &mut *ctx.accounts;
+        settle_cancellation_fees(
+            ctx.remaining_accounts,
+            &mut accounts.project,
+            |project, destination| {
+                let labour_token_account = InterfaceAccount::<TokenAccount>::try_from(destination)?;
+                require_keys_eq!(labour_token_account.mint, project.mint, ErrorCode::MintMismatch);
+
+                transfer_from_escrow(
+                    &accounts.token_program,
+                    &accounts.escrow_account,
+                    &labour_token_account,
+                    &accounts.mint,
+                    project,
+                    project_bump,
+                    project.cancellation_fee,
+                )?;
+
+                Ok(labour_token_account.owner)
             },
-            signer,
-        );
-        token::transfer(transfer_ctx, ctx.accounts.escrow_account.amount)?;
+        )?;
     }
 
-    // Mark project as closed or cancelled
-    ctx.accounts.project.status = status; // or ProjectStatus::Cancelled, based on your requirements
+    // Return the rest of the escrow to manager
+    if refund_amount > 0 {
+        transfer_from_escrow(
+            &ctx.accounts.token_program,
+            &ctx.accounts.escrow_account,
+            &ctx.accounts.manager_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.project,
+            ctx.bumps.project,
+            refund_amount,
+        )?;
+    }
 
     Ok(())
 }
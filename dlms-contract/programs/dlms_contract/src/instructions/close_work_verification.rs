@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::WorkVerification;
+
+#[derive(Accounts)]
+pub struct CloseWorkVerification<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [WORK_VERIFICATION.as_bytes(), work_verification.labour.as_ref(), work_verification.project.as_ref(), &work_verification.day_number.to_le_bytes()],
+        bump,
+        constraint = work_verification.payment_processed || work_verification.rejected @ ErrorCode::WorkDayNotSettled,
+        constraint = !work_verification.disputed @ ErrorCode::DisputeOpen
+    )]
+    pub work_verification: Account<'info, WorkVerification>,
+
+    /// CHECK: Receives the rent, must be whoever created the verification
+    #[account(
+        mut,
+        address = work_verification.rent_payer @ ErrorCode::NotAuthorized
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+// Anyone can close a settled day, the rent always goes back to its payer
+pub fn close_work_verification(ctx: Context<CloseWorkVerification>) -> Result<()> {
+    msg!(
+        "Closed verification for day {}, rent returned to {}",
+        ctx.accounts.work_verification.day_number,
+        ctx.accounts.rent_payer.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{
+    Assignment, EscrowAsset, NativeEscrow, NativeTreasury, Project, UserAccount, WorkVerification,
+};
+use crate::utils::{pay_native_wage, settle_confirmed_work_day};
+
+#[derive(Accounts)]
+pub struct ConfirmWorkDayNative<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = labour_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.labour == labour_account.key() @ ErrorCode::InvalidLabour,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        mut,
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump,
+        constraint = work_verification.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = work_verification.labour == labour_account.key() @ ErrorCode::InvalidLabour
+    )]
+    pub work_verification: Account<'info, WorkVerification>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        mut,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Option<Account<'info, NativeTreasury>>,
+
+    // The labour signs and is paid to their own wallet
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn confirm_work_day_native(ctx: Context<ConfirmWorkDayNative>, hours_worked: u8) -> Result<()> {
+    // The labour signs and is paid to their own wallet
+    let accounts = &mut *ctx.accounts;
+    settle_confirmed_work_day(
+        &mut accounts.work_verification,
+        &mut accounts.assignment,
+        &mut accounts.project,
+        hours_worked,
+        |project, wage| {
+            pay_native_wage(
+                &accounts.escrow_account.to_account_info(),
+                &accounts.authority.to_account_info(),
+                accounts.treasury_account.as_ref().map(AsRef::as_ref),
+                project,
+                wage,
+            )
+        },
+    )
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, NativeEscrow, NativeTreasury, PaymentMode, Project, ProjectStatus, SystemState, UserAccount, UserRole};
+
+#[derive(Accounts)]
+pub struct CreateNativeMilestoneProject<'info> {
+    #[account(
+        mut,
+        constraint = system_state.accepted_mints.contains(&native_mint::ID) @ ErrorCode::MintNotAccepted
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized,
+        constraint = manager_account.role == UserRole::Manager @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Project::INIT_SPACE,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &system_state.project_count.to_le_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NativeEscrow::INIT_SPACE,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Option<Account<'info, NativeTreasury>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_native_milestone_project(
+    ctx: Context<CreateNativeMilestoneProject>,
+    title: String,
+    metadata_uri: String,
+    max_labourers: u8,
+) -> Result<()> {
+    require!(max_labourers > 0, ErrorCode::InvalidLabourerCount);
+    ctx.accounts
+        .system_state
+        .require_treasury(ctx.accounts.treasury_account.as_ref())?;
+
+    let project = &mut ctx.accounts.project;
+    let system_state = &mut ctx.accounts.system_state;
+
+    ctx.accounts.escrow_account.project = project.key();
+
+    // Vault starts empty, each milestone is funded as it is added
+    project.manager = ctx.accounts.manager_account.key();
+    project.title = title;
+    project.metadata_uri = metadata_uri;
+    project.daily_rate = 0;
+    project.duration_days = 0;
+    project.max_labourers = max_labourers;
+    project.labour_count = 0;
+    project.status = ProjectStatus::Open;
+    project.escrow_account = ctx.accounts.escrow_account.key();
+    project.mint = native_mint::ID;
+    project.timestamp = Clock::get()?.unix_timestamp;
+    project.index = system_state.project_count;
+    project.pending_verifications = 0;
+    project.days_paid = 0;
+    project.payment_mode = PaymentMode::Milestone;
+    project.milestone_count = 0;
+    project.milestone_total = 0;
+    project.milestone_paid = 0;
+    project.fee_bps = system_state.fee_bps;
+    project.escrow_asset = EscrowAsset::Native;
+    project.streamed_paid = 0;
+    project.pending_milestone_total = 0;
+    project.active_streams = 0;
+    project.stream_owed = 0;
+    project.required_certifications = Vec::new();
+    project.cancellation_fee = 0;
+    project.daily_labourers = 0;
+    project.claimed_days = 0;
+    project.stream_committed = 0;
+
+    system_state.project_count += 1;
+
+    msg!("Native milestone project created with index: {}", project.index);
+
+    Ok(())
+}
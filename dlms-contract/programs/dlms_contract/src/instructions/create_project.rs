@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::constants::*;
 use crate::error::ErrorCode;
-use crate::states::{Project, ProjectStatus, SystemState, UserAccount, UserRole};
+use crate::states::{EscrowAsset, PaymentMode, Project, ProjectStatus, SystemState, UserAccount, UserRole};
+use crate::utils::{deposit_to_escrow, protocol_fee};
 
 #[derive(Accounts)]
 pub struct CreateProject<'info> {
@@ -32,25 +33,33 @@ pub struct CreateProject<'info> {
         payer = authority,
         token::mint = mint,
         token::authority = project,
+        token::token_program = token_program,
         seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
         bump
     )]
-    pub escrow_account: Account<'info, TokenAccount>,
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
-        constraint = manager_token_account.owner == authority.key() @ ErrorCode::WrongOwner
+        constraint = manager_token_account.owner == authority.key() @ ErrorCode::WrongOwner,
+        constraint = manager_token_account.mint == mint.key() @ ErrorCode::MintMismatch
     )]
-    pub manager_token_account: Account<'info, TokenAccount>,
+    pub manager_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
-        constraint = mint.key() == system_state.mint @ ErrorCode::MintMismatch
+        constraint = system_state.accepted_mints.contains(&mint.key()) @ ErrorCode::MintNotAccepted
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [TREASURY.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -74,7 +83,12 @@ pub fn create_project(
     require!(max_labourers > 0, ErrorCode::InvalidLabourerCount);
     msg!("Checked: max_labourers > 0");
 
-    let escrow_amount = daily_rate
+    ctx.accounts
+        .system_state
+        .require_treasury(ctx.accounts.treasury_account.as_ref())?;
+    msg!("Checked: treasury exists for the fee");
+
+    let wages_amount = daily_rate
         .checked_mul(max_labourers as u64)
         .ok_or_else(|| {
             msg!("Overflow on daily_rate * max_labourers");
@@ -86,24 +100,26 @@ pub fn create_project(
             ErrorCode::CalculationError
         })?;
 
-    msg!("Calculated escrow_amount: {}", escrow_amount);
+    // Protocol fee is escrowed on top of the wages and paid out alongside them
+    let fee_bps = ctx.accounts.system_state.fee_bps;
+    let escrow_amount = protocol_fee(wages_amount, fee_bps)
+        .and_then(|fee| wages_amount.checked_add(fee))
+        .ok_or_else(|| {
+            msg!("Overflow on wages + protocol fee");
+            ErrorCode::CalculationError
+        })?;
 
-    require!(
-        ctx.accounts.manager_token_account.amount >= escrow_amount,
-        ErrorCode::InsufficientFunds
-    );
-    msg!("Sufficient funds confirmed");
+    msg!("Calculated escrow_amount: {}", escrow_amount);
 
     msg!("Transferring tokens to escrow...");
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.manager_token_account.to_account_info(),
-            to: ctx.accounts.escrow_account.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
-        },
-    );
-    token::transfer(cpi_ctx, escrow_amount)?;
+    deposit_to_escrow(
+        &ctx.accounts.token_program,
+        &ctx.accounts.manager_token_account,
+        &mut ctx.accounts.escrow_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.authority,
+        escrow_amount,
+    )?;
     msg!("Token transfer complete");
 
     let project = &mut ctx.accounts.project;
@@ -118,8 +134,26 @@ pub fn create_project(
     project.labour_count = 0;
     project.status = ProjectStatus::Open;
     project.escrow_account = ctx.accounts.escrow_account.key();
+    project.mint = ctx.accounts.mint.key();
     project.timestamp = Clock::get()?.unix_timestamp;
     project.index = system_state.project_count;
+    project.pending_verifications = 0;
+    project.days_paid = 0;
+    project.payment_mode = PaymentMode::DailyRate;
+    project.milestone_count = 0;
+    project.milestone_total = 0;
+    project.milestone_paid = 0;
+    project.fee_bps = fee_bps;
+    project.escrow_asset = EscrowAsset::Token;
+    project.streamed_paid = 0;
+    project.pending_milestone_total = 0;
+    project.active_streams = 0;
+    project.stream_owed = 0;
+    project.required_certifications = Vec::new();
+    project.cancellation_fee = 0;
+    project.daily_labourers = 0;
+    project.claimed_days = 0;
+    project.stream_committed = 0;
 
     system_state.project_count += 1;
 
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{SkillProfile, UserAccount};
+
+#[derive(Accounts)]
+pub struct CreateSkillProfile<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = user_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SkillProfile::INIT_SPACE,
+        seeds = [SKILL_PROFILE.as_bytes(), user_account.key().as_ref()],
+        bump
+    )]
+    pub skill_profile: Account<'info, SkillProfile>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_skill_profile(ctx: Context<CreateSkillProfile>) -> Result<()> {
+    let skill_profile = &mut ctx.accounts.skill_profile;
+    skill_profile.user = ctx.accounts.user_account.key();
+    skill_profile.skills = Vec::new();
+
+    Ok(())
+}
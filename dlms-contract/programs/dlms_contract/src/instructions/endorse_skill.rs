@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Endorsement, Project, SkillProfile, UserAccount, UserRole};
+
+#[derive(Accounts)]
+#[instruction(skill_id: u16)]
+pub struct EndorseSkill<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized,
+        constraint = manager_account.role == UserRole::Manager @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [SKILL_PROFILE.as_bytes(), skill_profile.user.as_ref()],
+        bump
+    )]
+    pub skill_profile: Account<'info, SkillProfile>,
+
+    #[account(
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    // Proof the manager worked with the user on one of their projects
+    #[account(
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.labour == skill_profile.user @ ErrorCode::NoWorkingRelationship
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Endorsement::INIT_SPACE,
+        seeds = [ENDORSEMENT.as_bytes(), skill_profile.key().as_ref(), manager_account.key().as_ref(), &skill_id.to_le_bytes()],
+        bump
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn endorse_skill(ctx: Context<EndorseSkill>, skill_id: u16) -> Result<()> {
+    let skill_profile = &mut ctx.accounts.skill_profile;
+
+    let user_skill = skill_profile
+        .skills
+        .iter_mut()
+        .find(|user_skill| user_skill.skill_id == skill_id)
+        .ok_or(ErrorCode::SkillNotOnProfile)?;
+
+    user_skill.endorsements = user_skill
+        .endorsements
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    let endorsement = &mut ctx.accounts.endorsement;
+    endorsement.skill_profile = skill_profile.key();
+    endorsement.skill_id = skill_id;
+    endorsement.endorser = ctx.accounts.manager_account.key();
+    endorsement.project = ctx.accounts.project.key();
+    endorsement.timestamp = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{assignment, daily_rate_project, user_account};
+    use crate::states::UserSkill;
+    use crate::utils::tests::{
+        pda, process_with, program, program_account_at, program_account_with_space, read, signer,
+        system_account,
+    };
+
+    const SKILL_ID: u16 = 3;
+
+    // Accounts of an endorsement of SKILL_ID, in instruction order, by a manager whose project
+    // hired `hired` for a labour listing `skills`
+    fn endorsing(hired: bool, skills: &[u16]) -> [&'static AccountInfo<'static>; 7] {
+        let manager = Pubkey::new_unique();
+        let manager_key = pda(&[USER_STATE.as_bytes(), manager.as_ref()]);
+        let labour_key = pda(&[USER_STATE.as_bytes(), Pubkey::new_unique().as_ref()]);
+
+        let skill_profile_key = pda(&[SKILL_PROFILE.as_bytes(), labour_key.as_ref()]);
+        let skill_profile = SkillProfile {
+            user: labour_key,
+            skills: skills
+                .iter()
+                .map(|&skill_id| UserSkill {
+                    skill_id,
+                    endorsements: 0,
+                })
+                .collect(),
+        };
+
+        let mut project = daily_rate_project();
+        project.manager = manager_key;
+        let project_key = Pubkey::new_unique();
+
+        let mut assignment = assignment(0);
+        assignment.project = project_key;
+        assignment.labour = if hired { labour_key } else { Pubkey::new_unique() };
+
+        [
+            program_account_at(manager_key, &user_account(manager, UserRole::Manager)),
+            program_account_with_space(skill_profile_key, &skill_profile, 8 + SkillProfile::INIT_SPACE),
+            program_account_at(project_key, &project),
+            program_account_at(Pubkey::new_unique(), &assignment),
+            system_account(pda(&[
+                ENDORSEMENT.as_bytes(),
+                skill_profile_key.as_ref(),
+                manager_key.as_ref(),
+                &SKILL_ID.to_le_bytes(),
+            ])),
+            signer(manager),
+            program(anchor_lang::system_program::ID),
+        ]
+    }
+
+    fn endorse(accounts: &[&'static AccountInfo<'static>]) -> Result<()> {
+        process_with::<EndorseSkill>(accounts, &SKILL_ID.to_le_bytes(), |ctx| {
+            endorse_skill(ctx, SKILL_ID)
+        })
+    }
+
+    #[test]
+    fn manager_endorses_a_skill_of_labour_they_hired() {
+        let accounts = endorsing(true, &[1, SKILL_ID]);
+
+        endorse(&accounts).unwrap();
+
+        let skills = read::<SkillProfile>(accounts[1]).skills;
+        assert_eq!(skills[0].endorsements, 0);
+        assert_eq!(skills[1].endorsements, 1);
+
+        let endorsement = read::<Endorsement>(accounts[4]);
+        assert_eq!(endorsement.skill_profile, *accounts[1].key);
+        assert_eq!(endorsement.skill_id, SKILL_ID);
+        assert_eq!(endorsement.endorser, *accounts[0].key);
+        assert_eq!(endorsement.project, *accounts[2].key);
+    }
+
+    #[test]
+    fn only_listed_skills_of_hired_labour_are_endorsed() {
+        let result = endorse(&endorsing(false, &[SKILL_ID]));
+        assert_eq!(result.unwrap_err(), ErrorCode::NoWorkingRelationship.into());
+
+        let result = endorse(&endorsing(true, &[1]));
+        assert_eq!(result.unwrap_err(), ErrorCode::SkillNotOnProfile.into());
+    }
+}
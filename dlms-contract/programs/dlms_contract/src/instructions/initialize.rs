@@ -23,9 +23,15 @@ pub fn initialize_system(ctx: Context<InitializeSystem>, mint: Pubkey) -> Result
     let system_state = &mut ctx.accounts.system_state;
     system_state.authority = ctx.accounts.authority.key();
     system_state.mint = mint;
+    system_state.accepted_mints = vec![mint];
     system_state.labour_count = 0;
     system_state.manager_count = 0;
     system_state.project_count = 0;
+    system_state.approval_window = DEFAULT_APPROVAL_WINDOW;
+    system_state.fee_bps = 0;
+    system_state.reputation_half_life = DEFAULT_REPUTATION_HALF_LIFE;
+    system_state.skill_count = 0;
+    system_state.certification_type_count = 0;
 
     Ok(())
 }
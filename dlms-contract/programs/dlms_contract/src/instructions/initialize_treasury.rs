@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{NativeTreasury, SystemState};
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = system_state,
+        token::token_program = token_program,
+        seeds = [TREASURY.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = system_state.accepted_mints.contains(&mint.key()) @ ErrorCode::MintNotAccepted
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    msg!("Treasury created at {}", ctx.accounts.treasury_account.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeNativeTreasury<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NativeTreasury::INIT_SPACE,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Account<'info, NativeTreasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_native_treasury(ctx: Context<InitializeNativeTreasury>) -> Result<()> {
+    ctx.accounts.treasury_account.system_state = ctx.accounts.system_state.key();
+
+    msg!("Native treasury created at {}", ctx.accounts.treasury_account.key());
+
+    Ok(())
+}
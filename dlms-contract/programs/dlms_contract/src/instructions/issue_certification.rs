@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Certification, CertificationType, SystemState, UserAccount};
+
+#[derive(Accounts)]
+#[instruction(certification_type: u16)]
+pub struct IssueCertification<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        seeds = [USER_STATE.as_bytes(), user_account.authority.as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [CERTIFICATION_TYPE.as_bytes(), &certification_type.to_le_bytes()],
+        bump,
+        constraint = certification_type_account.active @ ErrorCode::CertificationTypeRetired
+    )]
+    pub certification_type_account: Account<'info, CertificationType>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Certification::INIT_SPACE,
+        seeds = [CERTIFICATION.as_bytes(), user_account.key().as_ref(), &certification_type.to_le_bytes()],
+        bump
+    )]
+    pub certification: Account<'info, Certification>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn issue_certification(
+    ctx: Context<IssueCertification>,
+    certification_type: u16,
+    evidence_uri: String,
+    expires_at: i64,
+) -> Result<()> {
+    require!(
+        evidence_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::EvidenceUriTooLong
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, ErrorCode::InvalidCertificationExpiry);
+
+    let certification = &mut ctx.accounts.certification;
+    certification.user = ctx.accounts.user_account.key();
+    certification.certification_type = certification_type;
+    certification.issuer = ctx.accounts.authority.key();
+    certification.evidence_uri = evidence_uri;
+    certification.issued_at = now;
+    certification.expires_at = expires_at;
+    certification.revoked = false;
+    certification.revoked_at = 0;
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, PaymentMode, Project, ProjectStatus, UserAccount, WorkVerification};
+
+#[derive(Accounts)]
+pub struct LogWorkDay<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.status == ProjectStatus::Open ||
+            project.status == ProjectStatus::InProgress @ ErrorCode::ProjectNotActive,
+        constraint = project.payment_mode == PaymentMode::DailyRate @ ErrorCode::WrongPaymentMode
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == assignment.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive,
+        constraint = !assignment.streaming @ ErrorCode::AssignmentStreaming
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + WorkVerification::INIT_SPACE,
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump
+    )]
+    pub work_verification: Account<'info, WorkVerification>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn log_work_day(
+    ctx: Context<LogWorkDay>,
+    day_number: u16,
+    work_metadata_uri: String,
+    hours_worked: u8,
+) -> Result<()> {
+    require!(
+        day_number == ctx.accounts.assignment.days_worked + 1,
+        ErrorCode::InvalidDayNumber
+    );
+
+    require!(
+        day_number <= ctx.accounts.project.duration_days,
+        ErrorCode::InvalidDayNumber
+    );
+
+    require!(
+        hours_worked > 0 && hours_worked <= HOURS_PER_DAY,
+        ErrorCode::InvalidHoursWorked
+    );
+
+    require!(
+        work_metadata_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::WorkMetadataUriTooLong
+    );
+
+    // Attendance recorded by the manager, payment waits for the labour to confirm it
+    let work_verification = &mut ctx.accounts.work_verification;
+    work_verification.project = ctx.accounts.project.key();
+    work_verification.labour = ctx.accounts.labour_account.key();
+    work_verification.day_number = day_number;
+    work_verification.hours_worked = hours_worked;
+    work_verification.manager_verified = true;
+    work_verification.labour_verified = false;
+    work_verification.metadata_uri = work_metadata_uri;
+    work_verification.timestamp = Clock::get()?.unix_timestamp;
+    work_verification.payment_processed = false;
+    work_verification.disputed = false;
+    work_verification.rejected = false;
+    work_verification.rent_payer = ctx.accounts.authority.key();
+
+    // Logged days are held back on close like claimed ones, the labour may still confirm them
+    ctx.accounts
+        .project
+        .claim_day(&mut ctx.accounts.assignment)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
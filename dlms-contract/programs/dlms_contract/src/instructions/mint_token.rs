@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
 
 use crate::constants::*;
 use crate::states::SystemState;
@@ -11,16 +11,16 @@ pub struct MintToken<'info> {
     pub system_state: Account<'info, SystemState>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// CHECK: We only derive this PDA in the backend and sign with seeds
     #[account(seeds = [b"mint"], bump)]
     pub mint_authority: UncheckedAccount<'info>,
 
     #[account(mut)]
-    pub to: Account<'info, TokenAccount>, // User's ATA
+    pub to: InterfaceAccount<'info, TokenAccount>, // User's ATA
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn mint_token(ctx: Context<MintToken>, amount: u64) -> Result<()> {
@@ -45,7 +45,7 @@ pub fn mint_token(ctx: Context<MintToken>, amount: u64) -> Result<()> {
         signer_seeds,
     );
 
-    token::mint_to(cpi_ctx, amount)?;
+    token_interface::mint_to(cpi_ctx, amount)?;
 
     msg!("Successfully minted tokens");
     Ok(())
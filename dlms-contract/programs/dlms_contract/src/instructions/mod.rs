@@ -13,6 +13,65 @@ pub mod approve_work_day;
 pub mod mark_user_spam;
 pub mod verify_user;
 pub mod mint_token;
+pub mod reject_application;
+pub mod withdraw_application;
+pub mod open_dispute;
+pub mod submit_dispute_evidence;
+pub mod resolve_dispute;
+pub mod set_approval_window;
+pub mod claim_work_day;
+pub mod update_project_status;
+pub mod amend_project;
+pub mod terminate_assignment;
+pub mod resign_assignment;
+pub mod create_milestone_project;
+pub mod add_milestone;
+pub mod assign_milestone;
+pub mod submit_milestone;
+pub mod approve_milestone;
+pub mod reject_milestone;
+pub mod approve_work_days_batch;
+pub mod log_work_day;
+pub mod confirm_work_day;
+pub mod pay_bonus;
+pub mod initialize_treasury;
+pub mod set_fee;
+pub mod withdraw_treasury;
+pub mod modify_accepted_mint;
+pub mod create_native_project;
+pub mod approve_work_day_native;
+pub mod close_native_project;
+pub mod pay_cancellation_fees;
+pub mod pay_cancellation_fees_native;
+pub mod claim_work_day_native;
+pub mod confirm_work_day_native;
+pub mod resolve_dispute_native;
+pub mod terminate_assignment_native;
+pub mod approve_work_days_batch_native;
+pub mod pay_bonus_native;
+pub mod start_stream;
+pub mod withdraw_stream;
+pub mod update_stream;
+pub mod close_work_verification;
+pub mod close_application;
+pub mod close_assignment;
+pub mod respond_to_review;
+pub mod moderate_review;
+pub mod set_reputation_half_life;
+pub mod refresh_weighted_rating;
+pub mod modify_skill;
+pub mod create_skill_profile;
+pub mod modify_user_skill;
+pub mod endorse_skill;
+pub mod issue_certification;
+pub mod modify_certification;
+pub mod modify_certification_type;
+pub mod set_required_certifications;
+pub mod close_dispute;
+pub mod amend_project_native;
+pub mod create_native_milestone_project;
+pub mod add_milestone_native;
+pub mod approve_milestone_native;
 
 pub use create_project::*;
 pub use initialize::*;
@@ -28,4 +87,63 @@ pub use verify_work_day::*;
 pub use approve_work_day::*;
 pub use mark_user_spam::*;
 pub use verify_user::*;
-pub use mint_token::*;
\ No newline at end of file
+pub use mint_token::*;
+pub use reject_application::*;
+pub use withdraw_application::*;
+pub use open_dispute::*;
+pub use submit_dispute_evidence::*;
+pub use resolve_dispute::*;
+pub use set_approval_window::*;
+pub use claim_work_day::*;
+pub use update_project_status::*;
+pub use amend_project::*;
+pub use terminate_assignment::*;
+pub use resign_assignment::*;
+pub use create_milestone_project::*;
+pub use add_milestone::*;
+pub use assign_milestone::*;
+pub use submit_milestone::*;
+pub use approve_milestone::*;
+pub use reject_milestone::*;
+pub use approve_work_days_batch::*;
+pub use log_work_day::*;
+pub use confirm_work_day::*;
+pub use pay_bonus::*;
+pub use initialize_treasury::*;
+pub use set_fee::*;
+pub use withdraw_treasury::*;
+pub use modify_accepted_mint::*;
+pub use create_native_project::*;
+pub use approve_work_day_native::*;
+pub use close_native_project::*;
+pub use pay_cancellation_fees::*;
+pub use pay_cancellation_fees_native::*;
+pub use claim_work_day_native::*;
+pub use confirm_work_day_native::*;
+pub use resolve_dispute_native::*;
+pub use terminate_assignment_native::*;
+pub use approve_work_days_batch_native::*;
+pub use pay_bonus_native::*;
+pub use start_stream::*;
+pub use withdraw_stream::*;
+pub use update_stream::*;
+pub use close_work_verification::*;
+pub use close_application::*;
+pub use close_assignment::*;
+pub use respond_to_review::*;
+pub use moderate_review::*;
+pub use set_reputation_half_life::*;
+pub use refresh_weighted_rating::*;
+pub use modify_skill::*;
+pub use create_skill_profile::*;
+pub use modify_user_skill::*;
+pub use endorse_skill::*;
+pub use issue_certification::*;
+pub use modify_certification::*;
+pub use modify_certification_type::*;
+pub use set_required_certifications::*;
+pub use close_dispute::*;
+pub use amend_project_native::*;
+pub use create_native_milestone_project::*;
+pub use add_milestone_native::*;
+pub use approve_milestone_native::*;
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Review, SystemState, UserAccount};
+
+#[derive(Accounts)]
+pub struct ModerateReview<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        constraint = !review.hidden @ ErrorCode::ReviewHidden
+    )]
+    pub review: Account<'info, Review>,
+
+    #[account(
+        mut,
+        seeds = [USER_STATE.as_bytes(), user_account.authority.as_ref()],
+        bump,
+        constraint = user_account.key() == review.reviewee @ ErrorCode::InvalidReviewee
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn moderate_review(ctx: Context<ModerateReview>, reason: String) -> Result<()> {
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        ErrorCode::ReasonTooLong
+    );
+
+    let review = &mut ctx.accounts.review;
+    let user = &mut ctx.accounts.user_account;
+
+    // Take the review out of the reviewee's aggregate as if it was never submitted
+    user.reputation
+        .remove(&review.scores)
+        .ok_or(ErrorCode::CalculationError)?;
+    user.rating_count = user
+        .rating_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    user.refresh_rating().ok_or(ErrorCode::CalculationError)?;
+
+    // The review is taken out of the weighted rating at the weight it has decayed to
+    let now = Clock::get()?.unix_timestamp;
+    let half_life = ctx.accounts.system_state.reputation_half_life;
+    let weight = review
+        .decay_weight(now, half_life)
+        .ok_or(ErrorCode::CalculationError)?;
+    user.apply_decay(now, half_life).ok_or(ErrorCode::CalculationError)?;
+    user.remove_weighted_review(&review.scores, weight)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    review.hidden = true;
+    review.moderation_reason = reason;
+    review.moderated_by = Some(ctx.accounts.authority.key());
+
+    msg!("Review {} hidden by moderation", review.key());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{review_at, scores, system_state, user_account};
+    use crate::states::UserRole;
+    use crate::utils::tests::{
+        pda, process, program_account_at, program_account_with_space, read, signer,
+    };
+
+    // Labour rated once with each of `ratings`, all submitted at time zero
+    fn rated(authority: Pubkey, ratings: &[u8]) -> UserAccount {
+        let mut user = user_account(authority, UserRole::Labour);
+        for &rating in ratings {
+            user.reputation.add(&scores(rating)).unwrap();
+            user.rating_count += 1;
+            user.refresh_rating().unwrap();
+            user.add_weighted_review(&scores(rating), DECAY_WEIGHT_SCALE).unwrap();
+        }
+        user
+    }
+
+    // Accounts of a moderation, signed by an admin when `by_admin`, in instruction order, of a
+    // review scoring `rating` given to a labour rated with `ratings`
+    fn moderating(by_admin: bool, rating: u8, ratings: &[u8]) -> [&'static AccountInfo<'static>; 4] {
+        let admin = Pubkey::new_unique();
+        let labour = Pubkey::new_unique();
+        let labour_key = pda(&[USER_STATE.as_bytes(), labour.as_ref()]);
+
+        let mut review = review_at(0);
+        review.reviewee = labour_key;
+        review.scores = scores(rating);
+
+        [
+            program_account_at(pda(&[INITIALIZE.as_bytes()]), &system_state(admin)),
+            program_account_with_space(Pubkey::new_unique(), &review, 8 + Review::INIT_SPACE),
+            program_account_at(labour_key, &rated(labour, ratings)),
+            signer(if by_admin { admin } else { Pubkey::new_unique() }),
+        ]
+    }
+
+    #[test]
+    fn hidden_review_no_longer_counts_towards_the_rating() {
+        let accounts = moderating(true, 1, &[5, 1]);
+
+        process::<ModerateReview>(&accounts, |ctx| moderate_review(ctx, "Abusive".to_string()))
+            .unwrap();
+
+        let review = read::<Review>(accounts[1]);
+        assert!(review.hidden);
+        assert_eq!(review.moderation_reason, "Abusive");
+        assert_eq!(review.moderated_by, Some(*accounts[3].key));
+
+        let user = read::<UserAccount>(accounts[2]);
+        let expected = rated(user.authority, &[5]);
+        assert_eq!(user.rating_count, 1);
+        assert_eq!(user.rating, expected.rating);
+        assert_eq!(user.weighted_rating, expected.weighted_rating);
+
+        let result = process::<ModerateReview>(&accounts, |ctx| moderate_review(ctx, String::new()));
+        assert_eq!(result.unwrap_err(), ErrorCode::ReviewHidden.into());
+    }
+
+    #[test]
+    fn only_admins_moderate_reviews() {
+        let accounts = moderating(false, 1, &[1]);
+
+        let result = process::<ModerateReview>(&accounts, |ctx| moderate_review(ctx, String::new()));
+
+        assert_eq!(result.unwrap_err(), ErrorCode::NotAuthorized.into());
+        assert_eq!(read::<UserAccount>(accounts[2]).rating_count, 1);
+    }
+
+    #[test]
+    fn moderation_reason_is_bounded() {
+        let accounts = moderating(true, 1, &[1]);
+
+        let result = process::<ModerateReview>(&accounts, |ctx| {
+            moderate_review(ctx, "a".repeat(MAX_REASON_LENGTH + 1))
+        });
+
+        assert_eq!(result.unwrap_err(), ErrorCode::ReasonTooLong.into());
+    }
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::SystemState;
+
+#[derive(Accounts)]
+pub struct ModifyAcceptedMint<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+    )]
+    pub system_state: Account<'info, SystemState>,
+}
+
+pub fn add_accepted_mint(ctx: Context<ModifyAcceptedMint>, mint: Pubkey) -> Result<()> {
+    let state = &mut ctx.accounts.system_state;
+
+    require!(
+        state.admins.contains(&ctx.accounts.authority.key()),
+        ErrorCode::NotAuthorized
+    );
+
+    require!(
+        !state.accepted_mints.contains(&mint),
+        ErrorCode::MintAlreadyAccepted
+    );
+
+    require!(
+        state.accepted_mints.len() < MAX_ACCEPTED_MINTS,
+        ErrorCode::MintLimitReached
+    );
+
+    state.accepted_mints.push(mint);
+
+    Ok(())
+}
+
+// Existing projects keep paying out in the mint their escrow was funded with
+pub fn remove_accepted_mint(ctx: Context<ModifyAcceptedMint>, mint: Pubkey) -> Result<()> {
+    let state = &mut ctx.accounts.system_state;
+
+    require!(
+        state.admins.contains(&ctx.accounts.authority.key()),
+        ErrorCode::NotAuthorized
+    );
+
+    let initial_len = state.accepted_mints.len();
+    state.accepted_mints.retain(|accepted| *accepted != mint);
+
+    require!(state.accepted_mints.len() < initial_len, ErrorCode::MintNotAccepted);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::system_state;
+    use crate::utils::tests::{pda, process, program_account_with_space, read, signer};
+
+    fn accounts(admin: Pubkey, authority: Pubkey) -> [&'static AccountInfo<'static>; 2] {
+        [
+            signer(authority),
+            program_account_with_space(
+                pda(&[INITIALIZE.as_bytes()]),
+                &system_state(admin),
+                8 + SystemState::INIT_SPACE,
+            ),
+        ]
+    }
+
+    #[test]
+    fn admin_adds_and_removes_accepted_mints() {
+        let admin = Pubkey::new_unique();
+        let accounts = accounts(admin, admin);
+        let mint = Pubkey::new_unique();
+
+        process::<ModifyAcceptedMint>(&accounts, |ctx| add_accepted_mint(ctx, mint)).unwrap();
+        assert_eq!(read::<SystemState>(accounts[1]).accepted_mints, vec![mint]);
+
+        let result = process::<ModifyAcceptedMint>(&accounts, |ctx| add_accepted_mint(ctx, mint));
+        assert_eq!(result.unwrap_err(), ErrorCode::MintAlreadyAccepted.into());
+
+        process::<ModifyAcceptedMint>(&accounts, |ctx| remove_accepted_mint(ctx, mint)).unwrap();
+        assert!(read::<SystemState>(accounts[1]).accepted_mints.is_empty());
+
+        let result = process::<ModifyAcceptedMint>(&accounts, |ctx| remove_accepted_mint(ctx, mint));
+        assert_eq!(result.unwrap_err(), ErrorCode::MintNotAccepted.into());
+    }
+
+    #[test]
+    fn only_admins_manage_accepted_mints() {
+        let accounts = accounts(Pubkey::new_unique(), Pubkey::new_unique());
+
+        let result = process::<ModifyAcceptedMint>(&accounts, |ctx| {
+            add_accepted_mint(ctx, Pubkey::new_unique())
+        });
+
+        assert_eq!(result.unwrap_err(), ErrorCode::NotAuthorized.into());
+    }
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Certification, SystemState};
+
+#[derive(Accounts)]
+pub struct ModifyCertification<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [CERTIFICATION.as_bytes(), certification.user.as_ref(), &certification.certification_type.to_le_bytes()],
+        bump
+    )]
+    pub certification: Account<'info, Certification>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn revoke_certification(ctx: Context<ModifyCertification>, reason: String) -> Result<()> {
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        ErrorCode::ReasonTooLong
+    );
+
+    let certification = &mut ctx.accounts.certification;
+    require!(!certification.revoked, ErrorCode::CertificationRevoked);
+
+    certification.revoked = true;
+    certification.revoked_at = Clock::get()?.unix_timestamp;
+
+    msg!("Certification {} revoked: {}", certification.key(), reason);
+
+    Ok(())
+}
+
+// Reissues an expired or revoked certification in place, the PDA is unique per user and type
+pub fn renew_certification(
+    ctx: Context<ModifyCertification>,
+    evidence_uri: String,
+    expires_at: i64,
+) -> Result<()> {
+    require!(
+        evidence_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::EvidenceUriTooLong
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, ErrorCode::InvalidCertificationExpiry);
+
+    let certification = &mut ctx.accounts.certification;
+    certification.issuer = ctx.accounts.authority.key();
+    certification.evidence_uri = evidence_uri;
+    certification.issued_at = now;
+    certification.expires_at = expires_at;
+    certification.revoked = false;
+    certification.revoked_at = 0;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{certification, system_state};
+    use crate::utils::tests::{
+        pda, process, program_account_at, program_account_with_space, read, signer,
+    };
+
+    // Accounts of a change by an admin, in instruction order, to a certification expiring at `expires_at`
+    fn modifying(expires_at: i64) -> [&'static AccountInfo<'static>; 3] {
+        let admin = Pubkey::new_unique();
+        let certification = certification(Pubkey::new_unique(), 1, expires_at);
+
+        [
+            program_account_at(pda(&[INITIALIZE.as_bytes()]), &system_state(admin)),
+            program_account_with_space(
+                pda(&[
+                    CERTIFICATION.as_bytes(),
+                    certification.user.as_ref(),
+                    &certification.certification_type.to_le_bytes(),
+                ]),
+                &certification,
+                8 + Certification::INIT_SPACE,
+            ),
+            signer(admin),
+        ]
+    }
+
+    #[test]
+    fn revoked_certification_is_renewed_in_place() {
+        let accounts = modifying(100);
+
+        process::<ModifyCertification>(&accounts, |ctx| revoke_certification(ctx, String::new()))
+            .unwrap();
+        assert!(!read::<Certification>(accounts[1]).is_valid(0));
+
+        let result = process::<ModifyCertification>(&accounts, |ctx| {
+            revoke_certification(ctx, String::new())
+        });
+        assert_eq!(result.unwrap_err(), ErrorCode::CertificationRevoked.into());
+
+        let uri = "https://example.com/evidence.json".to_string();
+        process::<ModifyCertification>(&accounts, |ctx| renew_certification(ctx, uri.clone(), 200))
+            .unwrap();
+
+        let certification = read::<Certification>(accounts[1]);
+        assert!(certification.is_valid(199));
+        assert_eq!(certification.issuer, *accounts[2].key);
+        assert_eq!(certification.evidence_uri, uri);
+    }
+
+    #[test]
+    fn renewed_certification_must_expire_later() {
+        let accounts = modifying(0);
+
+        let result = process::<ModifyCertification>(&accounts, |ctx| {
+            renew_certification(ctx, String::new(), 0)
+        });
+
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidCertificationExpiry.into());
+    }
+}
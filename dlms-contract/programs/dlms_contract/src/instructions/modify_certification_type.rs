@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{CertificationType, SystemState};
+
+#[derive(Accounts)]
+pub struct AddCertificationType<'info> {
+    #[account(
+        mut,
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CertificationType::INIT_SPACE,
+        seeds = [CERTIFICATION_TYPE.as_bytes(), &system_state.certification_type_count.to_le_bytes()],
+        bump
+    )]
+    pub certification_type: Account<'info, CertificationType>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RetireCertificationType<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [CERTIFICATION_TYPE.as_bytes(), &certification_type.id.to_le_bytes()],
+        bump,
+        constraint = certification_type.active @ ErrorCode::CertificationTypeRetired
+    )]
+    pub certification_type: Account<'info, CertificationType>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn add_certification_type(ctx: Context<AddCertificationType>, name: String) -> Result<()> {
+    require!(
+        name.len() <= MAX_CERTIFICATION_NAME_LENGTH,
+        ErrorCode::CertificationNameTooLong
+    );
+
+    let system_state = &mut ctx.accounts.system_state;
+    let certification_type = &mut ctx.accounts.certification_type;
+
+    certification_type.id = system_state.certification_type_count;
+    certification_type.name = name;
+    certification_type.active = true;
+    certification_type.timestamp = Clock::get()?.unix_timestamp;
+
+    system_state.certification_type_count = system_state
+        .certification_type_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
+
+// Certifications already issued for a retired type stay valid until they expire, no new ones can be issued
+pub fn retire_certification_type(ctx: Context<RetireCertificationType>) -> Result<()> {
+    ctx.accounts.certification_type.active = false;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Skill, SystemState};
+
+#[derive(Accounts)]
+pub struct AddSkill<'info> {
+    #[account(
+        mut,
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Skill::INIT_SPACE,
+        seeds = [SKILL.as_bytes(), &system_state.skill_count.to_le_bytes()],
+        bump
+    )]
+    pub skill: Account<'info, Skill>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RetireSkill<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [SKILL.as_bytes(), &skill.id.to_le_bytes()],
+        bump,
+        constraint = skill.active @ ErrorCode::SkillRetired
+    )]
+    pub skill: Account<'info, Skill>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn add_skill(ctx: Context<AddSkill>, name: String) -> Result<()> {
+    require!(name.len() <= MAX_SKILL_NAME_LENGTH, ErrorCode::SkillNameTooLong);
+
+    let system_state = &mut ctx.accounts.system_state;
+    let skill = &mut ctx.accounts.skill;
+
+    skill.id = system_state.skill_count;
+    skill.name = name;
+    skill.active = true;
+    skill.timestamp = Clock::get()?.unix_timestamp;
+
+    system_state.skill_count = system_state
+        .skill_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
+
+// Users who already list a retired skill keep it and its endorsements, it just can't be added anymore
+pub fn retire_skill(ctx: Context<RetireSkill>) -> Result<()> {
+    ctx.accounts.skill.active = false;
+
+    Ok(())
+}
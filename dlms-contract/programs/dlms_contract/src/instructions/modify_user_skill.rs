@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Skill, SkillProfile, UserAccount, UserSkill};
+
+#[derive(Accounts)]
+pub struct ModifyUserSkill<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = user_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [SKILL_PROFILE.as_bytes(), user_account.key().as_ref()],
+        bump
+    )]
+    pub skill_profile: Account<'info, SkillProfile>,
+
+    #[account(
+        seeds = [SKILL.as_bytes(), &skill.id.to_le_bytes()],
+        bump
+    )]
+    pub skill: Account<'info, Skill>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn add_user_skill(ctx: Context<ModifyUserSkill>) -> Result<()> {
+    let skill = &ctx.accounts.skill;
+    let skill_profile = &mut ctx.accounts.skill_profile;
+
+    require!(skill.active, ErrorCode::SkillRetired);
+
+    require!(
+        !skill_profile.skills.iter().any(|user_skill| user_skill.skill_id == skill.id),
+        ErrorCode::SkillAlreadyAdded
+    );
+
+    require!(
+        skill_profile.skills.len() < MAX_SKILLS,
+        ErrorCode::SkillLimitReached
+    );
+
+    skill_profile.skills.push(UserSkill {
+        skill_id: skill.id,
+        endorsements: 0,
+    });
+
+    Ok(())
+}
+
+// Endorsements are dropped with the tag, managers who already endorsed it can't endorse it again
+pub fn remove_user_skill(ctx: Context<ModifyUserSkill>) -> Result<()> {
+    let skill_id = ctx.accounts.skill.id;
+    let skill_profile = &mut ctx.accounts.skill_profile;
+
+    let initial_len = skill_profile.skills.len();
+    skill_profile.skills.retain(|user_skill| user_skill.skill_id != skill_id);
+
+    require!(skill_profile.skills.len() < initial_len, ErrorCode::SkillNotOnProfile);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::user_account;
+    use crate::states::UserRole;
+    use crate::utils::tests::{
+        pda, process, program_account_at, program_account_with_space, read, signer,
+    };
+
+    // Accounts of a change to the skills of a new labour, in instruction order, for the skill
+    // with `id` that is retired unless `active`
+    fn modifying(id: u16, active: bool) -> [&'static AccountInfo<'static>; 4] {
+        let labour = Pubkey::new_unique();
+        let labour_key = pda(&[USER_STATE.as_bytes(), labour.as_ref()]);
+        let skill_profile = SkillProfile {
+            user: labour_key,
+            skills: Vec::new(),
+        };
+        let skill = Skill {
+            id,
+            name: String::new(),
+            active,
+            timestamp: 0,
+        };
+
+        [
+            program_account_at(labour_key, &user_account(labour, UserRole::Labour)),
+            program_account_with_space(
+                pda(&[SKILL_PROFILE.as_bytes(), labour_key.as_ref()]),
+                &skill_profile,
+                8 + SkillProfile::INIT_SPACE,
+            ),
+            program_account_at(pda(&[SKILL.as_bytes(), &id.to_le_bytes()]), &skill),
+            signer(labour),
+        ]
+    }
+
+    fn skill_ids(skill_profile: &AccountInfo) -> Vec<u16> {
+        read::<SkillProfile>(skill_profile)
+            .skills
+            .iter()
+            .map(|user_skill| user_skill.skill_id)
+            .collect()
+    }
+
+    #[test]
+    fn skill_is_added_and_removed_once() {
+        let accounts = modifying(7, true);
+
+        process::<ModifyUserSkill>(&accounts, add_user_skill).unwrap();
+        assert_eq!(skill_ids(accounts[1]), vec![7]);
+
+        let result = process::<ModifyUserSkill>(&accounts, add_user_skill);
+        assert_eq!(result.unwrap_err(), ErrorCode::SkillAlreadyAdded.into());
+
+        process::<ModifyUserSkill>(&accounts, remove_user_skill).unwrap();
+        assert!(skill_ids(accounts[1]).is_empty());
+
+        let result = process::<ModifyUserSkill>(&accounts, remove_user_skill);
+        assert_eq!(result.unwrap_err(), ErrorCode::SkillNotOnProfile.into());
+    }
+
+    #[test]
+    fn retired_skill_cannot_be_added() {
+        let accounts = modifying(7, false);
+
+        let result = process::<ModifyUserSkill>(&accounts, add_user_skill);
+
+        assert_eq!(result.unwrap_err(), ErrorCode::SkillRetired.into());
+        assert!(skill_ids(accounts[1]).is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Dispute, DisputeStatus, Project, UserAccount, WorkVerification};
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = user_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [WORK_VERIFICATION.as_bytes(), work_verification.labour.as_ref(), project.key().as_ref(), &work_verification.day_number.to_le_bytes()],
+        bump,
+        constraint = work_verification.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub work_verification: Account<'info, WorkVerification>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [DISPUTE.as_bytes(), work_verification.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_dispute(ctx: Context<OpenDispute>, evidence_uri: String) -> Result<()> {
+    require!(
+        evidence_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::EvidenceUriTooLong
+    );
+
+    let opener = ctx.accounts.user_account.key();
+    let is_labour = opener == ctx.accounts.work_verification.labour;
+    let is_manager = opener == ctx.accounts.project.manager;
+
+    // Only the two parties of the work day can contest it
+    require!(is_labour || is_manager, ErrorCode::NotAuthorized);
+
+    let work_verification = &mut ctx.accounts.work_verification;
+
+    // A day logged by the manager can be contested by the labour instead of confirmed
+    require!(
+        work_verification.labour_verified || work_verification.manager_verified,
+        ErrorCode::WorkNotVerified
+    );
+    require!(!work_verification.payment_processed, ErrorCode::PaymentAlreadyProcessed);
+    require!(!work_verification.rejected, ErrorCode::WorkDayRejected);
+    require!(!work_verification.disputed, ErrorCode::DisputeOpen);
+
+    work_verification.disputed = true;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.work_verification = work_verification.key();
+    dispute.project = ctx.accounts.project.key();
+    dispute.labour = work_verification.labour;
+    dispute.opened_by = opener;
+    if is_labour {
+        dispute.labour_evidence_uri = evidence_uri;
+    } else {
+        dispute.manager_evidence_uri = evidence_uri;
+    }
+    dispute.status = DisputeStatus::Open;
+    dispute.ruling = None;
+    dispute.resolved_by = None;
+    dispute.timestamp = Clock::get()?.unix_timestamp;
+    dispute.resolved_at = 0;
+    dispute.rent_payer = ctx.accounts.authority.key();
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Bonus, BonusSource, Project, UserAccount};
+use crate::utils::{settle_bonus, transfer_from_escrow};
+
+#[derive(Accounts)]
+pub struct PayBonus<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == assignment.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Bonus::INIT_SPACE,
+        seeds = [BONUS.as_bytes(), assignment.key().as_ref(), &assignment.bonus_count.to_le_bytes()],
+        bump
+    )]
+    pub bonus: Account<'info, Bonus>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = manager_token_account.owner == authority.key() @ ErrorCode::WrongOwner,
+        constraint = manager_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub manager_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = labour_token_account.owner == labour_account.authority @ ErrorCode::WrongOwner,
+        constraint = labour_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub labour_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pay_bonus(
+    ctx: Context<PayBonus>,
+    amount: u64,
+    memo_uri: String,
+    source: BonusSource,
+) -> Result<()> {
+    let project_bump = ctx.bumps.project;
+    let escrow_balance = ctx.accounts.escrow_account.amount;
+    let accounts = &mut *ctx.accounts;
+    settle_bonus(
+        &mut accounts.bonus,
+        &mut accounts.assignment,
+        &accounts.project,
+        escrow_balance,
+        amount,
+        memo_uri,
+        source,
+        |source| match source {
+            BonusSource::EscrowSurplus => transfer_from_escrow(
+                &accounts.token_program,
+                &accounts.escrow_account,
+                &accounts.labour_token_account,
+                &accounts.mint,
+                &accounts.project,
+                project_bump,
+                amount,
+            ),
+            BonusSource::ManagerAccount => {
+                require!(
+                    accounts.manager_token_account.amount >= amount,
+                    ErrorCode::InsufficientFunds
+                );
+
+                let cpi_ctx = CpiContext::new(
+                    accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.manager_token_account.to_account_info(),
+                        mint: accounts.mint.to_account_info(),
+                        to: accounts.labour_token_account.to_account_info(),
+                        authority: accounts.authority.to_account_info(),
+                    },
+                );
+                token_interface::transfer_checked(cpi_ctx, amount, accounts.mint.decimals)
+            }
+        },
+    )
+}
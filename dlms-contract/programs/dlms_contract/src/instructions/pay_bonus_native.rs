@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{
+    Assignment, Bonus, BonusSource, EscrowAsset, NativeEscrow, Project, UserAccount,
+};
+use crate::utils::{settle_bonus, withdraw_lamports};
+
+#[derive(Accounts)]
+pub struct PayBonusNative<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == assignment.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Bonus::INIT_SPACE,
+        seeds = [BONUS.as_bytes(), assignment.key().as_ref(), &assignment.bonus_count.to_le_bytes()],
+        bump
+    )]
+    pub bonus: Account<'info, Bonus>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        mut,
+        address = labour_account.authority @ ErrorCode::WrongOwner
+    )]
+    pub labour_wallet: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pay_bonus_native(
+    ctx: Context<PayBonusNative>,
+    amount: u64,
+    memo_uri: String,
+    source: BonusSource,
+) -> Result<()> {
+    // Lamports above the vault's rent exempt balance are what it holds for wages
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let escrow_balance = escrow_info
+        .lamports()
+        .checked_sub(rent_exempt)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+
+    let accounts = &mut *ctx.accounts;
+    settle_bonus(
+        &mut accounts.bonus,
+        &mut accounts.assignment,
+        &accounts.project,
+        escrow_balance,
+        amount,
+        memo_uri,
+        source,
+        |source| match source {
+            BonusSource::EscrowSurplus => withdraw_lamports(
+                &escrow_info,
+                &accounts.labour_wallet.to_account_info(),
+                amount,
+            ),
+            BonusSource::ManagerAccount => {
+                require!(
+                    accounts.authority.lamports() >= amount,
+                    ErrorCode::InsufficientFunds
+                );
+
+                let cpi_ctx = CpiContext::new(
+                    accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: accounts.authority.to_account_info(),
+                        to: accounts.labour_wallet.to_account_info(),
+                    },
+                );
+                system_program::transfer(cpi_ctx, amount)
+            }
+        },
+    )
+}
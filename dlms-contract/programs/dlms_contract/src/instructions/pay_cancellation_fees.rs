@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, Project, ProjectStatus};
+use crate::utils::{settle_cancellation_fees, transfer_from_escrow};
+
+#[derive(Accounts)]
+pub struct PayCancellationFees<'info> {
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.status == ProjectStatus::Cancelled @ ErrorCode::WrongProjectStatus,
+        constraint = project.escrow_asset == EscrowAsset::Token @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Anyone may pay out the fees, they only ever go to the labour's own token accounts
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn pay_cancellation_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PayCancellationFees<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.project.cancellation_fee > 0,
+        ErrorCode::NoCancellationFee
+    );
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    // Each unpaid assignment is passed as an (assignment, labour token account) pair
+    let project_bump = ctx.bumps.project;
+    let accounts = &mut *ctx.accounts;
+    settle_cancellation_fees(
+        ctx.remaining_accounts,
+        &mut accounts.project,
+        |project, destination| {
+            let labour_token_account = InterfaceAccount::<TokenAccount>::try_from(destination)?;
+            require_keys_eq!(labour_token_account.mint, project.mint, ErrorCode::MintMismatch);
+
+            transfer_from_escrow(
+                &accounts.token_program,
+                &accounts.escrow_account,
+                &labour_token_account,
+                &accounts.mint,
+                project,
+                project_bump,
+                project.cancellation_fee,
+            )?;
+
+            Ok(labour_token_account.owner)
+        },
+    )?;
+
+    msg!(
+        "{} assignments still owed a cancellation fee",
+        ctx.accounts.project.labour_count
+    );
+
+    Ok(())
+}
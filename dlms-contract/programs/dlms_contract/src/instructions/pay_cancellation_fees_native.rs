@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{EscrowAsset, NativeEscrow, Project, ProjectStatus};
+use crate::utils::{settle_cancellation_fees, withdraw_lamports};
+
+#[derive(Accounts)]
+pub struct PayCancellationFeesNative<'info> {
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.status == ProjectStatus::Cancelled @ ErrorCode::WrongProjectStatus,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    // Anyone may pay out the fees, they only ever go to the labour's own wallets
+    pub authority: Signer<'info>,
+}
+
+pub fn pay_cancellation_fees_native<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PayCancellationFeesNative<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.project.cancellation_fee > 0,
+        ErrorCode::NoCancellationFee
+    );
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    // Each unpaid assignment is passed as an (assignment, labour wallet) pair
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    settle_cancellation_fees(
+        ctx.remaining_accounts,
+        &mut ctx.accounts.project,
+        |project, labour_wallet| {
+            withdraw_lamports(&escrow_info, labour_wallet, project.cancellation_fee)?;
+            Ok(*labour_wallet.key)
+        },
+    )?;
+
+    msg!(
+        "{} assignments still owed a cancellation fee",
+        ctx.accounts.project.labour_count
+    );
+
+    Ok(())
+}
@@ -5,6 +5,19 @@ use crate::error::ErrorCode;
 
 #[derive(Accounts)]
 pub struct RateUser<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = reviewer_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub reviewer_account: Account<'info, UserAccount>,
+
     #[account(
         mut,
         seeds = [USER_STATE.as_bytes(), user_account.authority.key().as_ref()],
@@ -12,11 +25,21 @@ pub struct RateUser<'info> {
     )]
     pub user_account: Account<'info, UserAccount>,
 
+    pub project: Account<'info, Project>,
+
+    // Proof the two users worked together, one as the labour and the other as the project manager
+    #[account(
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = (assignment.labour == reviewer_account.key() && project.manager == user_account.key()) ||
+            (assignment.labour == user_account.key() && project.manager == reviewer_account.key()) @ ErrorCode::NoWorkingRelationship
+    )]
+    pub assignment: Account<'info, Assignment>,
+
     #[account(
         init,
         payer = authority,
         space = 8 + Review::INIT_SPACE,
-        seeds = [REVIEW.as_bytes(), authority.key().as_ref(), user_account.key().as_ref()],
+        seeds = [REVIEW.as_bytes(), project.key().as_ref(), authority.key().as_ref(), user_account.key().as_ref()],
         bump
     )]
     pub review: Account<'info, Review>,
@@ -28,29 +51,134 @@ pub struct RateUser<'info> {
 }
 pub fn rate_user(
     ctx: Context<RateUser>,
-    rating: u8,
+    scores: ReviewScores,
     context: String,
 ) -> Result<()> {
-    require!(rating >= 1 && rating <= 5, ErrorCode::InvalidRating);
+    require!(scores.is_valid(), ErrorCode::InvalidRating);
 
     let user = &mut ctx.accounts.user_account;
 
-    // Update rating
-    let total_rating = user.rating.checked_mul(user.rating_count).unwrap_or(0) + rating as u32;
-    user.rating_count += 1;
-    user.rating = total_rating / user.rating_count;
+    // Keep running sums so the averages never lose precision
+    user.reputation.add(&scores).ok_or(ErrorCode::CalculationError)?;
+    user.rating_count = user
+        .rating_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    user.refresh_rating().ok_or(ErrorCode::CalculationError)?;
+
+    // Older reviews fade before the new one is added at full weight
+    let now = Clock::get()?.unix_timestamp;
+    user.apply_decay(now, ctx.accounts.system_state.reputation_half_life)
+        .ok_or(ErrorCode::CalculationError)?;
+    user.add_weighted_review(&scores, DECAY_WEIGHT_SCALE)
+        .ok_or(ErrorCode::CalculationError)?;
 
     // Save review
     let review = &mut ctx.accounts.review;
     review.reviewer = ctx.accounts.authority.key();
     review.reviewee = user.key();
-    review.rating = rating;
+    review.project = ctx.accounts.project.key();
+    review.scores = scores;
     review.context = context;
-    review.timestamp = Clock::get()?.unix_timestamp;
+    review.timestamp = now;
     review.review_type = match user.role {
         UserRole::Labour => ReviewType::LabourReview,
         UserRole::Manager => ReviewType::ManagerReview,
     };
+    review.response_uri = String::new();
+    review.hidden = false;
+    review.moderation_reason = String::new();
+    review.moderated_by = None;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{assignment, daily_rate_project, scores, system_state, user_account};
+    use crate::utils::tests::{pda, process, program, program_account_at, read, signer, system_account};
+
+    // Accounts of a review by `reviewer` of `reviewee`, in instruction order, where the
+    // assignment hired `labour` on a project run by `manager`
+    fn rating(
+        reviewer: Pubkey,
+        reviewee: Pubkey,
+        manager: Pubkey,
+        labour: Pubkey,
+    ) -> [&'static AccountInfo<'static>; 8] {
+        let user_key = |wallet: Pubkey| pda(&[USER_STATE.as_bytes(), wallet.as_ref()]);
+        let role = |wallet: Pubkey| {
+            if wallet == manager {
+                UserRole::Manager
+            } else {
+                UserRole::Labour
+            }
+        };
+
+        let mut project = daily_rate_project();
+        project.manager = user_key(manager);
+        let project_key = Pubkey::new_unique();
+
+        let mut assignment = assignment(0);
+        assignment.project = project_key;
+        assignment.labour = user_key(labour);
+
+        [
+            program_account_at(pda(&[INITIALIZE.as_bytes()]), &system_state(Pubkey::new_unique())),
+            program_account_at(user_key(reviewer), &user_account(reviewer, role(reviewer))),
+            program_account_at(user_key(reviewee), &user_account(reviewee, role(reviewee))),
+            program_account_at(project_key, &project),
+            program_account_at(Pubkey::new_unique(), &assignment),
+            system_account(pda(&[
+                REVIEW.as_bytes(),
+                project_key.as_ref(),
+                reviewer.as_ref(),
+                user_key(reviewee).as_ref(),
+            ])),
+            signer(reviewer),
+            program(anchor_lang::system_program::ID),
+        ]
+    }
+
+    #[test]
+    fn labour_rates_the_manager_of_their_project() {
+        let (manager, labour) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = rating(labour, manager, manager, labour);
+
+        process::<RateUser>(&accounts, |ctx| rate_user(ctx, scores(4), String::new())).unwrap();
+
+        let review = read::<Review>(accounts[5]);
+        assert_eq!(review.reviewer, labour);
+        assert_eq!(review.reviewee, *accounts[2].key);
+        assert_eq!(review.project, *accounts[3].key);
+        assert!(review.review_type == ReviewType::ManagerReview);
+        assert_eq!(read::<UserAccount>(accounts[2]).rating_count, 1);
+    }
+
+    #[test]
+    fn manager_rates_the_labour_they_hired() {
+        let (manager, labour) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = rating(manager, labour, manager, labour);
+
+        process::<RateUser>(&accounts, |ctx| rate_user(ctx, scores(5), String::new())).unwrap();
+
+        assert!(read::<Review>(accounts[5]).review_type == ReviewType::LabourReview);
+    }
+
+    #[test]
+    fn users_who_never_worked_together_cannot_rate_each_other() {
+        let (manager, labour) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        // Another labour of the same manager
+        let accounts = rating(Pubkey::new_unique(), manager, manager, labour);
+        let result = process::<RateUser>(&accounts, |ctx| rate_user(ctx, scores(1), String::new()));
+        assert_eq!(result.unwrap_err(), ErrorCode::NoWorkingRelationship.into());
+
+        // Two labourers hired on the same project
+        let accounts = rating(Pubkey::new_unique(), labour, manager, labour);
+        let result = process::<RateUser>(&accounts, |ctx| rate_user(ctx, scores(1), String::new()));
+        assert_eq!(result.unwrap_err(), ErrorCode::NoWorkingRelationship.into());
+    }
+}
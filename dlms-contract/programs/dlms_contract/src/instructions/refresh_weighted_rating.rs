@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{SystemState, UserAccount};
+
+#[derive(Accounts)]
+pub struct RefreshWeightedRating<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [USER_STATE.as_bytes(), user_account.authority.as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+}
+
+// Reviews are folded into the decayed sums as they are submitted, this only applies the decay since
+pub fn refresh_weighted_rating(ctx: Context<RefreshWeightedRating>) -> Result<()> {
+    let half_life = ctx.accounts.system_state.reputation_half_life;
+    let now = Clock::get()?.unix_timestamp;
+
+    let user = &mut ctx.accounts.user_account;
+    user.apply_decay(now, half_life).ok_or(ErrorCode::CalculationError)?;
+
+    msg!(
+        "Weighted rating {} from {} reviews",
+        user.weighted_rating,
+        user.rating_count
+    );
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::ErrorCode;
-use crate::states::{SystemState, UserAccount, UserRole};
+use crate::states::{Reputation, SystemState, UserAccount, UserRole};
 
 #[derive(Accounts)]
 pub struct RegisterUser<'info> {
@@ -39,6 +39,11 @@ pub fn register_user(
     user_account.verified = false;
     user_account.rating = 0;
     user_account.rating_count = 0;
+    user_account.reputation = Reputation::default();
+    user_account.weighted_rating = 0;
+    user_account.weighted_rating_at = 0;
+    user_account.decayed_score_sum = 0;
+    user_account.decayed_weight = 0;
     user_account.timestamp = Clock::get()?.unix_timestamp;
     user_account.index = system_state.labour_count + system_state.manager_count;
     user_account.spam = false;
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Project, UserAccount, Application, ApplicationStatus};
+
+#[derive(Accounts)]
+pub struct RejectApplication<'info> {
+    #[account(
+        mut,
+        seeds = [APPLICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        close = labour_authority,
+        constraint = application.status == ApplicationStatus::Pending @ ErrorCode::ApplicationNotPending
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        constraint = application.labour == labour_account.key() @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    /// CHECK: Receives the application rent, checked against the labour account authority
+    #[account(
+        mut,
+        constraint = labour_authority.key() == labour_account.authority @ ErrorCode::InvalidLabour
+    )]
+    pub labour_authority: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = application.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn reject_application(ctx: Context<RejectApplication>, reason: String) -> Result<()> {
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        ErrorCode::ReasonTooLong
+    );
+
+    let application = &ctx.accounts.application;
+
+    msg!(
+        "Application of {} to project {} rejected: {}",
+        application.labour,
+        application.project,
+        reason
+    );
+
+    // Account is closed on exit and its rent returned to the labour
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{daily_rate_project, user_account};
+    use crate::states::UserRole;
+    use crate::utils::tests::{pda, process, program_account_at, read, signer, system_account};
+
+    // Accounts of a rejection, in instruction order, for an application in `status`
+    fn rejection(status: ApplicationStatus) -> [&'static AccountInfo<'static>; 6] {
+        let manager = Pubkey::new_unique();
+        let labour = Pubkey::new_unique();
+        let manager_key = pda(&[USER_STATE.as_bytes(), manager.as_ref()]);
+        let labour_key = pda(&[USER_STATE.as_bytes(), labour.as_ref()]);
+
+        let mut project = daily_rate_project();
+        project.manager = manager_key;
+        let project_key = pda(&[PROJECT_SEED.as_bytes(), manager_key.as_ref(), &project.index.to_le_bytes()]);
+
+        let application = Application {
+            labour: labour_key,
+            project: project_key,
+            description: String::new(),
+            status,
+            timestamp: 0,
+        };
+
+        [
+            program_account_at(
+                pda(&[APPLICATION.as_bytes(), labour_key.as_ref(), project_key.as_ref()]),
+                &application,
+            ),
+            program_account_at(labour_key, &user_account(labour, UserRole::Labour)),
+            system_account(labour),
+            program_account_at(project_key, &project),
+            program_account_at(manager_key, &user_account(manager, UserRole::Manager)),
+            signer(manager),
+        ]
+    }
+
+    #[test]
+    fn rejected_application_is_closed_to_the_labour() {
+        let accounts = rejection(ApplicationStatus::Pending);
+        let [application, _, labour_authority, ..] = accounts;
+        let rent = application.lamports();
+
+        process::<RejectApplication>(&accounts, |ctx| {
+            reject_application(ctx, "Positions filled".to_string())
+        })
+        .unwrap();
+
+        assert_eq!(labour_authority.lamports(), rent);
+        assert!(application.data_is_empty());
+    }
+
+    #[test]
+    fn only_pending_applications_can_be_rejected() {
+        let accounts = rejection(ApplicationStatus::Accepted);
+
+        let result = process::<RejectApplication>(&accounts, |ctx| {
+            reject_application(ctx, String::new())
+        });
+
+        assert_eq!(result.unwrap_err(), ErrorCode::ApplicationNotPending.into());
+        assert!(read::<Application>(accounts[0]).status == ApplicationStatus::Accepted);
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Milestone, Project, ProjectStatus, UserAccount};
+
+#[derive(Accounts)]
+pub struct RejectMilestone<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        // Once the project is closed a submitted milestone can only be approved, its funds are held back
+        constraint = project.status == ProjectStatus::Open ||
+            project.status == ProjectStatus::InProgress @ ErrorCode::ProjectNotActive
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE.as_bytes(), project.key().as_ref(), &[milestone.index]],
+        bump,
+        constraint = milestone.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn reject_milestone(ctx: Context<RejectMilestone>, reason: String) -> Result<()> {
+    require!(
+        reason.len() <= MAX_REASON_LENGTH,
+        ErrorCode::ReasonTooLong
+    );
+
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(milestone.labour_verified, ErrorCode::MilestoneNotSubmitted);
+    require!(!milestone.manager_verified, ErrorCode::AlreadyVerified);
+
+    // Send the milestone back to the assignee, the funds stay in escrow
+    milestone.labour_verified = false;
+    milestone.timestamp = Clock::get()?.unix_timestamp;
+
+    let project = &mut ctx.accounts.project;
+    project.pending_verifications = project
+        .pending_verifications
+        .checked_sub(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    project.pending_milestone_total = project
+        .pending_milestone_total
+        .checked_sub(milestone.amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!("Milestone {} submission rejected: {}", milestone.index, reason);
+
+    Ok(())
+}
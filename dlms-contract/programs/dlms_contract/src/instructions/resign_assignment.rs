@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Project, ProjectStatus, Stream, UserAccount};
+
+#[derive(Accounts)]
+pub struct ResignAssignment<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = labour_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.labour == labour_account.key() @ ErrorCode::InvalidLabour,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    // Only passed for streaming assignments, it is stopped along with the assignment
+    #[account(
+        mut,
+        seeds = [STREAM.as_bytes(), assignment.key().as_ref()],
+        bump,
+        constraint = !stream.stopped @ ErrorCode::StreamStopped
+    )]
+    pub stream: Option<Account<'info, Stream>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn resign_assignment(ctx: Context<ResignAssignment>) -> Result<()> {
+    require!(
+        ctx.accounts.project.status == ProjectStatus::InProgress ||
+        ctx.accounts.project.status == ProjectStatus::Open,
+        ErrorCode::ProjectNotActive
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let project = &mut ctx.accounts.project;
+
+    // Wages streamed so far stay withdrawable, the same as stop_stream
+    if ctx.accounts.assignment.streaming {
+        let stream = ctx
+            .accounts
+            .stream
+            .as_mut()
+            .ok_or(ErrorCode::StreamRequired)?;
+
+        stream.checkpoint(now).ok_or(ErrorCode::CalculationError)?;
+        stream.stopped = true;
+
+        project.release_stream(stream).ok_or(ErrorCode::CalculationError)?;
+    } else {
+        project
+            .release_daily_labourer(&ctx.accounts.assignment)
+            .ok_or(ErrorCode::CalculationError)?;
+    }
+
+    // Days verified by both sides are already paid. A day still waiting on the
+    // manager stays payable through approve_work_day, claim_work_day or a dispute.
+    let assignment = &mut ctx.accounts.assignment;
+    assignment.active = false;
+    assignment.resigned = true;
+    assignment.ended_at = now;
+
+    // Free the slot so the manager can backfill it, approve_application checks the escrow
+    // still covers a full slot since the days paid so far have left it
+    project.labour_count = project
+        .labour_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    if project.status == ProjectStatus::InProgress {
+        project.status = ProjectStatus::Open;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{
+    Assignment, Dispute, DisputeRuling, DisputeStatus, EscrowAsset, NativeEscrow, NativeTreasury,
+    Project, SystemState, UserAccount, WorkVerification,
+};
+use crate::utils::{pay_native_wage, settle_dispute};
+
+#[derive(Accounts)]
+pub struct ResolveDisputeNative<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        constraint = system_state.admins.contains(&authority.key()) @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE.as_bytes(), work_verification.key().as_ref()],
+        bump,
+        constraint = dispute.status == DisputeStatus::Open @ ErrorCode::DisputeNotOpen
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = dispute.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == dispute.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.labour == labour_account.key() @ ErrorCode::InvalidLabour,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        mut,
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump,
+        constraint = work_verification.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = work_verification.labour == labour_account.key() @ ErrorCode::InvalidLabour
+    )]
+    pub work_verification: Account<'info, WorkVerification>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        mut,
+        address = labour_account.authority @ ErrorCode::WrongOwner
+    )]
+    pub labour_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Option<Account<'info, NativeTreasury>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn resolve_dispute_native(ctx: Context<ResolveDisputeNative>, ruling: DisputeRuling) -> Result<()> {
+    let resolved_by = ctx.accounts.authority.key();
+    let accounts = &mut *ctx.accounts;
+    settle_dispute(
+        &mut accounts.dispute,
+        &mut accounts.work_verification,
+        &mut accounts.assignment,
+        &mut accounts.project,
+        ruling,
+        resolved_by,
+        |project, wage| {
+            pay_native_wage(
+                &accounts.escrow_account.to_account_info(),
+                &accounts.labour_wallet.to_account_info(),
+                accounts.treasury_account.as_ref().map(AsRef::as_ref),
+                project,
+                wage,
+            )
+        },
+    )
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Review, UserAccount};
+
+#[derive(Accounts)]
+pub struct RespondToReview<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = user_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        constraint = review.reviewee == user_account.key() @ ErrorCode::InvalidReviewee,
+        constraint = review.response_uri.is_empty() @ ErrorCode::ReviewAlreadyAnswered
+    )]
+    pub review: Account<'info, Review>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn respond_to_review(ctx: Context<RespondToReview>, response_uri: String) -> Result<()> {
+    // An empty response would leave the review looking unanswered while blocking a real one
+    require!(
+        !response_uri.is_empty() && response_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::InvalidResponseUri
+    );
+
+    ctx.accounts.review.response_uri = response_uri;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{review_at, user_account};
+    use crate::states::UserRole;
+    use crate::utils::tests::{
+        pda, process, program_account_at, program_account_with_space, read, signer,
+    };
+
+    // Accounts of a response by `responder`, in instruction order, to a review of `reviewee`
+    fn responding(responder: Pubkey, reviewee: Pubkey) -> [&'static AccountInfo<'static>; 3] {
+        let user_key = pda(&[USER_STATE.as_bytes(), responder.as_ref()]);
+
+        let mut review = review_at(0);
+        review.reviewee = pda(&[USER_STATE.as_bytes(), reviewee.as_ref()]);
+
+        [
+            program_account_at(user_key, &user_account(responder, UserRole::Labour)),
+            program_account_with_space(Pubkey::new_unique(), &review, 8 + Review::INIT_SPACE),
+            signer(responder),
+        ]
+    }
+
+    #[test]
+    fn reviewee_answers_a_review_once() {
+        let reviewee = Pubkey::new_unique();
+        let accounts = responding(reviewee, reviewee);
+        let uri = "https://example.com/response.json".to_string();
+
+        process::<RespondToReview>(&accounts, |ctx| respond_to_review(ctx, uri.clone())).unwrap();
+        assert_eq!(read::<Review>(accounts[1]).response_uri, uri);
+
+        let result = process::<RespondToReview>(&accounts, |ctx| respond_to_review(ctx, uri));
+        assert_eq!(result.unwrap_err(), ErrorCode::ReviewAlreadyAnswered.into());
+    }
+
+    #[test]
+    fn response_uri_must_be_set_and_bounded() {
+        let reviewee = Pubkey::new_unique();
+        let accounts = responding(reviewee, reviewee);
+
+        for uri in [String::new(), "a".repeat(MAX_URI_LENGTH + 1)] {
+            let result = process::<RespondToReview>(&accounts, |ctx| respond_to_review(ctx, uri));
+            assert_eq!(result.unwrap_err(), ErrorCode::InvalidResponseUri.into());
+        }
+        assert!(read::<Review>(accounts[1]).response_uri.is_empty());
+    }
+
+    #[test]
+    fn only_the_reviewee_answers() {
+        let accounts = responding(Pubkey::new_unique(), Pubkey::new_unique());
+
+        let result = process::<RespondToReview>(&accounts, |ctx| {
+            respond_to_review(ctx, "https://example.com/response.json".to_string())
+        });
+
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidReviewee.into());
+    }
+}
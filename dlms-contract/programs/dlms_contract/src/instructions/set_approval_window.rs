@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::SystemState;
+
+#[derive(Accounts)]
+pub struct SetApprovalWindow<'info> {
+    #[account(
+        mut,
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_approval_window(ctx: Context<SetApprovalWindow>, approval_window: i64) -> Result<()> {
+    require!(approval_window > 0, ErrorCode::InvalidApprovalWindow);
+
+    ctx.accounts.system_state.approval_window = approval_window;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::SystemState;
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(
+        mut,
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_fee(ctx: Context<SetFee>, fee_bps: u16) -> Result<()> {
+    require!(fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidFee);
+
+    // Applies to projects created from now on, existing escrows keep the fee they were funded with
+    ctx.accounts.system_state.fee_bps = fee_bps;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::SystemState;
+
+#[derive(Accounts)]
+pub struct SetReputationHalfLife<'info> {
+    #[account(
+        mut,
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_reputation_half_life(ctx: Context<SetReputationHalfLife>, half_life: i64) -> Result<()> {
+    require!(half_life > 0, ErrorCode::InvalidHalfLife);
+
+    ctx.accounts.system_state.reputation_half_life = half_life;
+
+    Ok(())
+}
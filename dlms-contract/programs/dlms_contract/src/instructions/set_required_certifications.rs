@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{CertificationType, Project, ProjectStatus, UserAccount};
+
+#[derive(Accounts)]
+pub struct SetRequiredCertifications<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.status == ProjectStatus::Open ||
+            project.status == ProjectStatus::InProgress @ ErrorCode::ProjectNotActive
+    )]
+    pub project: Account<'info, Project>,
+
+    pub authority: Signer<'info>,
+}
+
+// Only applies to applications and approvals from now on, existing assignments are kept.
+// The registry entry of every type is passed in remaining accounts, in ascending type order.
+pub fn set_required_certifications<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetRequiredCertifications<'info>>,
+    certification_types: Vec<u16>,
+) -> Result<()> {
+    require!(
+        certification_types.len() <= MAX_REQUIRED_CERTIFICATIONS,
+        ErrorCode::TooManyCertifications
+    );
+
+    let mut certification_types = certification_types;
+    certification_types.sort_unstable();
+    certification_types.dedup();
+
+    require!(
+        ctx.remaining_accounts.len() == certification_types.len(),
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    for (certification_type, type_info) in certification_types.iter().zip(ctx.remaining_accounts) {
+        // Registry entries are program owned and their id is fixed on creation
+        let registered = Account::<CertificationType>::try_from(type_info)?;
+        require!(
+            registered.id == *certification_type,
+            ErrorCode::InvalidCertificationType
+        );
+        require!(registered.active, ErrorCode::CertificationTypeRetired);
+    }
+
+    ctx.accounts.project.required_certifications = certification_types;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::tests::{daily_rate_project, user_account};
+    use crate::states::UserRole;
+    use crate::utils::tests::{
+        pda, process, program_account_at, program_account_with_space, read, signer,
+    };
+
+    fn certification_type(id: u16, active: bool) -> &'static AccountInfo<'static> {
+        let certification_type = CertificationType {
+            id,
+            name: String::new(),
+            active,
+            timestamp: 0,
+        };
+        program_account_at(
+            pda(&[CERTIFICATION_TYPE.as_bytes(), &id.to_le_bytes()]),
+            &certification_type,
+        )
+    }
+
+    // Accounts of a change by the manager, in instruction order, followed by `registry`
+    fn setting(registry: &[&'static AccountInfo<'static>]) -> Vec<&'static AccountInfo<'static>> {
+        let manager = Pubkey::new_unique();
+        let manager_key = pda(&[USER_STATE.as_bytes(), manager.as_ref()]);
+
+        let mut project = daily_rate_project();
+        project.manager = manager_key;
+
+        let mut accounts = vec![
+            program_account_at(manager_key, &user_account(manager, UserRole::Manager)),
+            program_account_with_space(
+                pda(&[PROJECT_SEED.as_bytes(), manager_key.as_ref(), &project.index.to_le_bytes()]),
+                &project,
+                8 + Project::INIT_SPACE,
+            ),
+            signer(manager),
+        ];
+        accounts.extend_from_slice(registry);
+        accounts
+    }
+
+    #[test]
+    fn requirements_are_stored_sorted_and_deduplicated() {
+        let accounts = setting(&[certification_type(1, true), certification_type(4, true)]);
+
+        process::<SetRequiredCertifications>(&accounts, |ctx| {
+            set_required_certifications(ctx, vec![4, 1, 4])
+        })
+        .unwrap();
+
+        assert_eq!(read::<Project>(accounts[1]).required_certifications, vec![1, 4]);
+    }
+
+    #[test]
+    fn every_requirement_needs_its_active_registry_entry() {
+        let accounts = setting(&[certification_type(2, true)]);
+        let result = process::<SetRequiredCertifications>(&accounts, |ctx| {
+            set_required_certifications(ctx, vec![1])
+        });
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidCertificationType.into());
+
+        let accounts = setting(&[certification_type(1, false)]);
+        let result = process::<SetRequiredCertifications>(&accounts, |ctx| {
+            set_required_certifications(ctx, vec![1])
+        });
+        assert_eq!(result.unwrap_err(), ErrorCode::CertificationTypeRetired.into());
+
+        let accounts = setting(&[]);
+        let result = process::<SetRequiredCertifications>(&accounts, |ctx| {
+            set_required_certifications(ctx, vec![1])
+        });
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidRemainingAccounts.into());
+    }
+}
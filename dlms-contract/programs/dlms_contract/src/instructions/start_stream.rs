@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, EscrowAsset, PaymentMode, Project, ProjectStatus, Stream, UserAccount};
+
+#[derive(Accounts)]
+pub struct StartStream<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.status == ProjectStatus::Open ||
+            project.status == ProjectStatus::InProgress @ ErrorCode::ProjectNotActive,
+        constraint = project.payment_mode == PaymentMode::DailyRate @ ErrorCode::WrongPaymentMode,
+        constraint = project.escrow_asset == EscrowAsset::Token @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == assignment.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive,
+        constraint = !assignment.streaming @ ErrorCode::AssignmentStreaming
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    /// CHECK: Verification of the first work day, it must not exist for the stream to start
+    #[account(
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump
+    )]
+    pub work_verification: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Stream::INIT_SPACE,
+        seeds = [STREAM.as_bytes(), assignment.key().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn start_stream(ctx: Context<StartStream>) -> Result<()> {
+    // Streams accrue from the assignment start, so no day may have been paid or recorded already
+    require!(
+        ctx.accounts.assignment.days_worked == 0 &&
+        ctx.accounts.work_verification.data_is_empty(),
+        ErrorCode::UnsettledWorkDays
+    );
+
+    let project = &mut ctx.accounts.project;
+    let assignment = &mut ctx.accounts.assignment;
+    let stream = &mut ctx.accounts.stream;
+
+    // A stream can never pay out more than one labour slot of the project budget
+    let cap = project
+        .daily_rate
+        .checked_mul(project.duration_days as u64)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    stream.assignment = assignment.key();
+    stream.project = project.key();
+    stream.labour = assignment.labour;
+    stream.rate_per_day = project.daily_rate;
+    stream.cap = cap;
+    stream.accrued = 0;
+    stream.withdrawn = 0;
+    stream.last_checkpoint = assignment.timestamp;
+    stream.paused = false;
+    stream.stopped = false;
+    stream.timestamp = Clock::get()?.unix_timestamp;
+
+    assignment.streaming = true;
+
+    project
+        .commit_stream(assignment, stream)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Dispute, DisputeStatus, Project, UserAccount};
+
+#[derive(Accounts)]
+pub struct SubmitDisputeEvidence<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = user_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        constraint = dispute.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE.as_bytes(), dispute.work_verification.as_ref()],
+        bump,
+        constraint = dispute.status == DisputeStatus::Open @ ErrorCode::DisputeNotOpen
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn submit_dispute_evidence(
+    ctx: Context<SubmitDisputeEvidence>,
+    evidence_uri: String,
+) -> Result<()> {
+    require!(
+        evidence_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::EvidenceUriTooLong
+    );
+
+    let submitter = ctx.accounts.user_account.key();
+    let dispute = &mut ctx.accounts.dispute;
+
+    if submitter == dispute.labour {
+        dispute.labour_evidence_uri = evidence_uri;
+    } else if submitter == ctx.accounts.project.manager {
+        dispute.manager_evidence_uri = evidence_uri;
+    } else {
+        return Err(error!(ErrorCode::NotAuthorized));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Milestone, Project, ProjectStatus, UserAccount};
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = labour_account.authority == authority.key() @ ErrorCode::NotAuthorized,
+        constraint = labour_account.active @ ErrorCode::LabourNotActive
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        constraint = project.status == ProjectStatus::Open ||
+            project.status == ProjectStatus::InProgress @ ErrorCode::ProjectNotActive
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE.as_bytes(), project.key().as_ref(), &[milestone.index]],
+        bump,
+        constraint = milestone.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = milestone.assignee == Some(labour_account.key()) @ ErrorCode::MilestoneNotAssigned
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn submit_milestone(ctx: Context<SubmitMilestone>, submission_uri: String) -> Result<()> {
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(!milestone.labour_verified, ErrorCode::AlreadyVerified);
+    require!(
+        submission_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::MilestoneUriTooLong
+    );
+
+    milestone.labour_verified = true;
+    milestone.submission_uri = submission_uri;
+    milestone.timestamp = Clock::get()?.unix_timestamp;
+
+    let project = &mut ctx.accounts.project;
+    project.pending_verifications = project
+        .pending_verifications
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    project.pending_milestone_total = project
+        .pending_milestone_total
+        .checked_add(milestone.amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
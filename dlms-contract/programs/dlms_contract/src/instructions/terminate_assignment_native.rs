@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{
+    Assignment, EscrowAsset, NativeEscrow, NativeTreasury, Project, TerminationReason, UserAccount,
+};
+use crate::utils::{pay_native_wage, settle_termination};
+
+#[derive(Accounts)]
+pub struct TerminateAssignmentNative<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager,
+        constraint = project.escrow_asset == EscrowAsset::Native @ ErrorCode::WrongEscrowAsset
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = labour_account.key() == assignment.labour @ ErrorCode::InvalidLabour
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = assignment.active @ ErrorCode::AssignmentNotActive,
+        constraint = !assignment.streaming @ ErrorCode::AssignmentStreaming
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    /// CHECK: Verification of the next unpaid day, it may not exist yet and is deserialized only if it does
+    #[account(
+        mut,
+        seeds = [WORK_VERIFICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref(), &(assignment.days_worked + 1).to_le_bytes()],
+        bump
+    )]
+    pub work_verification: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: Account<'info, NativeEscrow>,
+
+    #[account(
+        mut,
+        address = labour_account.authority @ ErrorCode::WrongOwner
+    )]
+    pub labour_wallet: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Option<Account<'info, NativeTreasury>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn terminate_assignment_native(
+    ctx: Context<TerminateAssignmentNative>,
+    reason: TerminationReason,
+) -> Result<()> {
+    // Work the labour already verified is paid before they are removed
+    let accounts = &mut *ctx.accounts;
+    settle_termination(
+        &accounts.work_verification.to_account_info(),
+        &mut accounts.assignment,
+        &mut accounts.project,
+        None,
+        reason,
+        |project, wage| {
+            pay_native_wage(
+                &accounts.escrow_account.to_account_info(),
+                &accounts.labour_wallet.to_account_info(),
+                accounts.treasury_account.as_ref().map(AsRef::as_ref),
+                project,
+                wage,
+            )
+        },
+    )
+}
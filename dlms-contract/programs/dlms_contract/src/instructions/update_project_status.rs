@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Project, UserAccount};
+
+#[derive(Accounts)]
+pub struct UpdateProjectStatus<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn start_project(ctx: Context<UpdateProjectStatus>) -> Result<()> {
+    ctx.accounts.project.start()
+}
+
+pub fn complete_project(ctx: Context<UpdateProjectStatus>) -> Result<()> {
+    ctx.accounts.project.complete()
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Project, ProjectStatus, Stream, UserAccount};
+
+#[derive(Accounts)]
+pub struct UpdateStream<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = manager_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub manager_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), manager_account.key().as_ref(), &project.index.to_le_bytes()],
+        bump,
+        constraint = project.manager == manager_account.key() @ ErrorCode::InvalidManager
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        mut,
+        seeds = [STREAM.as_bytes(), assignment.key().as_ref()],
+        bump,
+        constraint = !stream.stopped @ ErrorCode::StreamStopped
+    )]
+    pub stream: Account<'info, Stream>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn pause_stream(ctx: Context<UpdateStream>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    require!(!stream.paused, ErrorCode::StreamPaused);
+
+    stream
+        .checkpoint(Clock::get()?.unix_timestamp)
+        .ok_or(ErrorCode::CalculationError)?;
+    stream.paused = true;
+
+    Ok(())
+}
+
+pub fn resume_stream(ctx: Context<UpdateStream>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+    require!(stream.paused, ErrorCode::StreamNotPaused);
+
+    // Time spent paused does not accrue
+    stream.last_checkpoint = Clock::get()?.unix_timestamp;
+    stream.paused = false;
+
+    Ok(())
+}
+
+pub fn stop_stream(ctx: Context<UpdateStream>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // Wages accrued so far stay withdrawable by the labour
+    let stream = &mut ctx.accounts.stream;
+    stream.checkpoint(now).ok_or(ErrorCode::CalculationError)?;
+    stream.stopped = true;
+
+    let project = &mut ctx.accounts.project;
+    project.release_stream(stream).ok_or(ErrorCode::CalculationError)?;
+
+    let assignment = &mut ctx.accounts.assignment;
+    if assignment.active {
+        assignment.active = false;
+        assignment.ended_at = now;
+
+        // Free the slot so the manager can backfill it, approve_application checks the escrow
+        // still covers a full slot since what the stream paid has left it
+        project.labour_count = project
+            .labour_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::CalculationError)?;
+        if project.status == ProjectStatus::InProgress {
+            project.status = ProjectStatus::Open;
+        }
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::ErrorCode;
-use crate::states::{Project, ProjectStatus, UserAccount, UserRole, Application, ApplicationStatus, WorkVerification, Assignment};
+use crate::states::{Project, ProjectStatus, PaymentMode, UserAccount, UserRole, Application, ApplicationStatus, WorkVerification, Assignment};
 
 #[derive(Accounts)]
 pub struct VerifyWorkDay<'info> {
@@ -15,7 +15,9 @@ pub struct VerifyWorkDay<'info> {
     
     #[account(
         mut,
-        constraint = project.status == ProjectStatus::Open @ ErrorCode::ProjectNotOpen
+        constraint = project.status == ProjectStatus::Open ||
+            project.status == ProjectStatus::InProgress @ ErrorCode::ProjectNotActive,
+        constraint = project.payment_mode == PaymentMode::DailyRate @ ErrorCode::WrongPaymentMode
     )]
     pub project: Account<'info, Project>,
     
@@ -24,7 +26,8 @@ pub struct VerifyWorkDay<'info> {
         seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
         bump,
         constraint = assignment.labour == labour_account.key() @ ErrorCode::InvalidLabour,
-        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject,
+        constraint = !assignment.streaming @ ErrorCode::AssignmentStreaming
     )]
     pub assignment: Account<'info, Assignment>,
     
@@ -46,7 +49,8 @@ pub struct VerifyWorkDay<'info> {
  pub fn verify_work_day(
         ctx: Context<VerifyWorkDay>,
         day_number: u16,
-        work_metadata_uri: String
+        work_metadata_uri: String,
+        hours_worked: u8
     ) -> Result<()> {
         require!(
             ctx.accounts.project.status == ProjectStatus::InProgress ||
@@ -64,16 +68,40 @@ pub struct VerifyWorkDay<'info> {
             ErrorCode::InvalidDayNumber
         );
         
+        require!(
+            day_number <= ctx.accounts.project.duration_days,
+            ErrorCode::InvalidDayNumber
+        );
+        
+        require!(
+            hours_worked > 0 && hours_worked <= HOURS_PER_DAY,
+            ErrorCode::InvalidHoursWorked
+        );
+        
+        require!(
+            work_metadata_uri.len() <= MAX_URI_LENGTH,
+            ErrorCode::WorkMetadataUriTooLong
+        );
+        
         // Create work verification
         let work_verification = &mut ctx.accounts.work_verification;
         work_verification.project = ctx.accounts.project.key();
         work_verification.labour = ctx.accounts.labour_account.key();
         work_verification.day_number = day_number;
+        work_verification.hours_worked = hours_worked;
         work_verification.manager_verified = false;
         work_verification.labour_verified = true;
         work_verification.metadata_uri = work_metadata_uri;
         work_verification.timestamp = Clock::get()?.unix_timestamp;
         work_verification.payment_processed = false;
+        work_verification.disputed = false;
+        work_verification.rejected = false;
+        work_verification.rent_payer = ctx.accounts.authority.key();
+        
+        ctx.accounts
+            .project
+            .claim_day(&mut ctx.accounts.assignment)
+            .ok_or(ErrorCode::CalculationError)?;
         
         
         Ok(())
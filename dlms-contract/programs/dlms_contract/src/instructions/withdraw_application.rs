@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Project, UserAccount, Application, ApplicationStatus};
+
+#[derive(Accounts)]
+pub struct WithdrawApplication<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = labour_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        constraint = application.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [APPLICATION.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        close = authority,
+        constraint = application.labour == labour_account.key() @ ErrorCode::InvalidLabour,
+        constraint = application.status == ApplicationStatus::Pending @ ErrorCode::ApplicationNotPending
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn withdraw_application(ctx: Context<WithdrawApplication>) -> Result<()> {
+    let application = &ctx.accounts.application;
+
+    msg!(
+        "Application of {} to project {} withdrawn",
+        application.labour,
+        application.project
+    );
+
+    // Account is closed on exit and its rent returned to the labour
+    Ok(())
+}
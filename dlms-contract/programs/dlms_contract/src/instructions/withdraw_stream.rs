@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{Assignment, Project, Stream, UserAccount};
+use crate::utils::pay_wage;
+
+#[derive(Accounts)]
+pub struct WithdrawStream<'info> {
+    #[account(
+        seeds = [USER_STATE.as_bytes(), authority.key().as_ref()],
+        bump,
+        constraint = labour_account.authority == authority.key() @ ErrorCode::NotAuthorized
+    )]
+    pub labour_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [PROJECT_SEED.as_bytes(), project.manager.as_ref(), &project.index.to_le_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [ASSIGNMENT.as_bytes(), labour_account.key().as_ref(), project.key().as_ref()],
+        bump,
+        constraint = assignment.labour == labour_account.key() @ ErrorCode::InvalidLabour,
+        constraint = assignment.project == project.key() @ ErrorCode::InvalidProject
+    )]
+    pub assignment: Account<'info, Assignment>,
+
+    #[account(
+        mut,
+        seeds = [STREAM.as_bytes(), assignment.key().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED.as_bytes(), project.key().as_ref()],
+        bump,
+        constraint = escrow_account.key() == project.escrow_account @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = project.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = labour_token_account.owner == authority.key() @ ErrorCode::WrongOwner,
+        constraint = labour_token_account.mint == project.mint @ ErrorCode::MintMismatch
+    )]
+    pub labour_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY.as_bytes(), project.mint.as_ref()],
+        bump
+    )]
+    pub treasury_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_stream(ctx: Context<WithdrawStream>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let stream = &mut ctx.accounts.stream;
+    stream.checkpoint(now).ok_or(ErrorCode::CalculationError)?;
+
+    let amount = stream
+        .accrued
+        .checked_sub(stream.withdrawn)
+        .ok_or(ErrorCode::CalculationError)?;
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+
+    pay_wage(
+        &ctx.accounts.token_program,
+        &ctx.accounts.escrow_account,
+        &ctx.accounts.labour_token_account,
+        ctx.accounts.treasury_account.as_ref(),
+        &ctx.accounts.mint,
+        &ctx.accounts.project,
+        ctx.bumps.project,
+        amount,
+    )?;
+
+    stream.withdrawn = stream
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    let project = &mut ctx.accounts.project;
+    project.streamed_paid = project
+        .streamed_paid
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    // A stopped stream's balance is held back on the project until it is withdrawn,
+    // a running one is still committed up to its cap
+    if stream.stopped {
+        project.stream_owed = project
+            .stream_owed
+            .checked_sub(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+    } else {
+        project.stream_committed = project
+            .stream_committed
+            .checked_sub(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+    }
+
+    msg!("Withdrew {} streamed wages", amount);
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{NativeTreasury, SystemState};
+use crate::utils::withdraw_lamports;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY.as_bytes(), treasury_account.mint.as_ref()],
+        bump
+    )]
+    pub treasury_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = treasury_account.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(
+        ctx.accounts.treasury_account.amount >= amount,
+        ErrorCode::InsufficientFunds
+    );
+
+    let seeds = &[INITIALIZE.as_bytes(), &[ctx.bumps.system_state]];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.treasury_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.system_state.to_account_info(),
+        },
+        signer,
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawNativeTreasury<'info> {
+    #[account(
+        seeds = [INITIALIZE.as_bytes()],
+        bump,
+        has_one = authority @ ErrorCode::NotAuthorized
+    )]
+    pub system_state: Account<'info, SystemState>,
+
+    #[account(
+        mut,
+        seeds = [NATIVE_TREASURY.as_bytes()],
+        bump
+    )]
+    pub treasury_account: Account<'info, NativeTreasury>,
+
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+// Only the collected fees can be withdrawn, the treasury keeps its rent exemption
+pub fn withdraw_native_treasury(ctx: Context<WithdrawNativeTreasury>, amount: u64) -> Result<()> {
+    withdraw_lamports(
+        &ctx.accounts.treasury_account.to_account_info(),
+        &ctx.accounts.destination.to_account_info(),
+        amount,
+    )
+}
@@ -4,6 +4,7 @@ pub mod constants;
 pub mod error;
 pub mod instructions;
 pub mod states;
+pub mod utils;
 
 pub use error::*;
 pub use instructions::*;
@@ -60,19 +61,20 @@ pub mod dlms_contract {
         instructions::create_project(ctx, title, metadata_uri, daily_rate, duration_days, max_labourers)
     }
 
-    pub fn close_project(
-        ctx: Context<CloseProject>, 
-        status: ProjectStatus
+    pub fn close_project<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseProject<'info>>,
+        status: ProjectStatus,
+        cancellation_fee: u64,
     ) -> Result<()> {
-        instructions::close_project(ctx, status)
+        instructions::close_project(ctx, status, cancellation_fee)
     }
 
     pub fn rate_user(
         ctx: Context<RateUser>,
-        rating: u8,
+        scores: ReviewScores,
         context: String,
     ) -> Result<()> {
-        instructions::rate_user(ctx, rating, context)
+        instructions::rate_user(ctx, scores, context)
     }
 
     pub fn apply_to_project(
@@ -91,15 +93,17 @@ pub mod dlms_contract {
     pub fn verify_work_day(
         ctx: Context<VerifyWorkDay>,
         day_number: u16,
-        work_metadata_uri: String
+        work_metadata_uri: String,
+        hours_worked: u8
     ) -> Result<()> {
-        instructions::verify_work_day(ctx, day_number, work_metadata_uri)
+        instructions::verify_work_day(ctx, day_number, work_metadata_uri, hours_worked)
     }
 
     pub fn approve_work_day(
-        ctx: Context<ApproveWorkDay>
+        ctx: Context<ApproveWorkDay>,
+        hours_worked: u8
     ) -> Result<()> {
-        instructions::approve_work_day(ctx)
+        instructions::approve_work_day(ctx, hours_worked)
     }
 
     pub fn mark_user_as_spam(
@@ -121,4 +125,447 @@ pub mod dlms_contract {
     ) -> Result<()> {
         instructions::mint_token(ctx, amount)
     }
+
+    pub fn reject_application(
+        ctx: Context<RejectApplication>,
+        reason: String
+    ) -> Result<()> {
+        instructions::reject_application(ctx, reason)
+    }
+
+    pub fn withdraw_application(
+        ctx: Context<WithdrawApplication>
+    ) -> Result<()> {
+        instructions::withdraw_application(ctx)
+    }
+
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        evidence_uri: String
+    ) -> Result<()> {
+        instructions::open_dispute(ctx, evidence_uri)
+    }
+
+    pub fn submit_dispute_evidence(
+        ctx: Context<SubmitDisputeEvidence>,
+        evidence_uri: String
+    ) -> Result<()> {
+        instructions::submit_dispute_evidence(ctx, evidence_uri)
+    }
+
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        ruling: DisputeRuling
+    ) -> Result<()> {
+        instructions::resolve_dispute(ctx, ruling)
+    }
+
+    pub fn set_approval_window(
+        ctx: Context<SetApprovalWindow>,
+        approval_window: i64
+    ) -> Result<()> {
+        instructions::set_approval_window(ctx, approval_window)
+    }
+
+    pub fn claim_work_day(
+        ctx: Context<ClaimWorkDay>
+    ) -> Result<()> {
+        instructions::claim_work_day(ctx)
+    }
+
+    pub fn start_project(
+        ctx: Context<UpdateProjectStatus>
+    ) -> Result<()> {
+        instructions::start_project(ctx)
+    }
+
+    pub fn complete_project(
+        ctx: Context<UpdateProjectStatus>
+    ) -> Result<()> {
+        instructions::complete_project(ctx)
+    }
+
+    pub fn amend_project(
+        ctx: Context<AmendProject>,
+        deposit_amount: u64,
+        duration_days: Option<u16>,
+        daily_rate: Option<u64>,
+    ) -> Result<()> {
+        instructions::amend_project(ctx, deposit_amount, duration_days, daily_rate)
+    }
+
+    pub fn terminate_assignment(
+        ctx: Context<TerminateAssignment>,
+        reason: TerminationReason
+    ) -> Result<()> {
+        instructions::terminate_assignment(ctx, reason)
+    }
+
+    pub fn resign_assignment(
+        ctx: Context<ResignAssignment>
+    ) -> Result<()> {
+        instructions::resign_assignment(ctx)
+    }
+
+    pub fn create_milestone_project(
+        ctx: Context<CreateMilestoneProject>,
+        title: String,
+        metadata_uri: String,
+        max_labourers: u8,
+    ) -> Result<()> {
+        instructions::create_milestone_project(ctx, title, metadata_uri, max_labourers)
+    }
+
+    pub fn add_milestone(
+        ctx: Context<AddMilestone>,
+        amount: u64,
+        description_uri: String
+    ) -> Result<()> {
+        instructions::add_milestone(ctx, amount, description_uri)
+    }
+
+    pub fn assign_milestone(
+        ctx: Context<AssignMilestone>
+    ) -> Result<()> {
+        instructions::assign_milestone(ctx)
+    }
+
+    pub fn submit_milestone(
+        ctx: Context<SubmitMilestone>,
+        submission_uri: String
+    ) -> Result<()> {
+        instructions::submit_milestone(ctx, submission_uri)
+    }
+
+    pub fn approve_milestone(
+        ctx: Context<ApproveMilestone>
+    ) -> Result<()> {
+        instructions::approve_milestone(ctx)
+    }
+
+    pub fn reject_milestone(
+        ctx: Context<RejectMilestone>,
+        reason: String
+    ) -> Result<()> {
+        instructions::reject_milestone(ctx, reason)
+    }
+
+    pub fn approve_work_days_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApproveWorkDaysBatch<'info>>,
+        hours_worked: Vec<u8>,
+    ) -> Result<()> {
+        instructions::approve_work_days_batch(ctx, hours_worked)
+    }
+
+    pub fn log_work_day(
+        ctx: Context<LogWorkDay>,
+        day_number: u16,
+        work_metadata_uri: String,
+        hours_worked: u8
+    ) -> Result<()> {
+        instructions::log_work_day(ctx, day_number, work_metadata_uri, hours_worked)
+    }
+
+    pub fn confirm_work_day(
+        ctx: Context<ConfirmWorkDay>,
+        hours_worked: u8
+    ) -> Result<()> {
+        instructions::confirm_work_day(ctx, hours_worked)
+    }
+
+    pub fn pay_bonus(
+        ctx: Context<PayBonus>,
+        amount: u64,
+        memo_uri: String,
+        source: BonusSource,
+    ) -> Result<()> {
+        instructions::pay_bonus(ctx, amount, memo_uri, source)
+    }
+
+    pub fn initialize_treasury(
+        ctx: Context<InitializeTreasury>
+    ) -> Result<()> {
+        instructions::initialize_treasury(ctx)
+    }
+
+    pub fn set_fee(
+        ctx: Context<SetFee>,
+        fee_bps: u16
+    ) -> Result<()> {
+        instructions::set_fee(ctx, fee_bps)
+    }
+
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64
+    ) -> Result<()> {
+        instructions::withdraw_treasury(ctx, amount)
+    }
+
+    pub fn add_accepted_mint(ctx: Context<ModifyAcceptedMint>, mint: Pubkey) -> Result<()> {
+        instructions::add_accepted_mint(ctx, mint)
+    }
+
+    pub fn remove_accepted_mint(ctx: Context<ModifyAcceptedMint>, mint: Pubkey) -> Result<()> {
+        instructions::remove_accepted_mint(ctx, mint)
+    }
+
+    pub fn create_native_project(
+        ctx: Context<CreateNativeProject>,
+        title: String,
+        metadata_uri: String,
+        daily_rate: u64,
+        duration_days: u16,
+        max_labourers: u8,
+    ) -> Result<()> {
+        instructions::create_native_project(ctx, title, metadata_uri, daily_rate, duration_days, max_labourers)
+    }
+
+    pub fn approve_work_day_native(
+        ctx: Context<ApproveWorkDayNative>,
+        hours_worked: u8
+    ) -> Result<()> {
+        instructions::approve_work_day_native(ctx, hours_worked)
+    }
+
+    pub fn close_native_project<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseNativeProject<'info>>,
+        status: ProjectStatus,
+        cancellation_fee: u64,
+    ) -> Result<()> {
+        instructions::close_native_project(ctx, status, cancellation_fee)
+    }
+
+    pub fn pay_cancellation_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PayCancellationFees<'info>>
+    ) -> Result<()> {
+        instructions::pay_cancellation_fees(ctx)
+    }
+
+    pub fn pay_cancellation_fees_native<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PayCancellationFeesNative<'info>>
+    ) -> Result<()> {
+        instructions::pay_cancellation_fees_native(ctx)
+    }
+
+    pub fn claim_work_day_native(
+        ctx: Context<ClaimWorkDayNative>
+    ) -> Result<()> {
+        instructions::claim_work_day_native(ctx)
+    }
+
+    pub fn confirm_work_day_native(
+        ctx: Context<ConfirmWorkDayNative>,
+        hours_worked: u8
+    ) -> Result<()> {
+        instructions::confirm_work_day_native(ctx, hours_worked)
+    }
+
+    pub fn resolve_dispute_native(
+        ctx: Context<ResolveDisputeNative>,
+        ruling: DisputeRuling
+    ) -> Result<()> {
+        instructions::resolve_dispute_native(ctx, ruling)
+    }
+
+    pub fn terminate_assignment_native(
+        ctx: Context<TerminateAssignmentNative>,
+        reason: TerminationReason
+    ) -> Result<()> {
+        instructions::terminate_assignment_native(ctx, reason)
+    }
+
+    pub fn approve_work_days_batch_native<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApproveWorkDaysBatchNative<'info>>,
+        hours_worked: Vec<u8>,
+    ) -> Result<()> {
+        instructions::approve_work_days_batch_native(ctx, hours_worked)
+    }
+
+    pub fn pay_bonus_native(
+        ctx: Context<PayBonusNative>,
+        amount: u64,
+        memo_uri: String,
+        source: BonusSource,
+    ) -> Result<()> {
+        instructions::pay_bonus_native(ctx, amount, memo_uri, source)
+    }
+
+    pub fn withdraw_native_treasury(
+        ctx: Context<WithdrawNativeTreasury>,
+        amount: u64
+    ) -> Result<()> {
+        instructions::withdraw_native_treasury(ctx, amount)
+    }
+
+    pub fn initialize_native_treasury(
+        ctx: Context<InitializeNativeTreasury>
+    ) -> Result<()> {
+        instructions::initialize_native_treasury(ctx)
+    }
+
+    pub fn start_stream(ctx: Context<StartStream>) -> Result<()> {
+        instructions::start_stream(ctx)
+    }
+
+    pub fn withdraw_stream(ctx: Context<WithdrawStream>) -> Result<()> {
+        instructions::withdraw_stream(ctx)
+    }
+
+    pub fn pause_stream(ctx: Context<UpdateStream>) -> Result<()> {
+        instructions::pause_stream(ctx)
+    }
+
+    pub fn resume_stream(ctx: Context<UpdateStream>) -> Result<()> {
+        instructions::resume_stream(ctx)
+    }
+
+    pub fn stop_stream(ctx: Context<UpdateStream>) -> Result<()> {
+        instructions::stop_stream(ctx)
+    }
+
+    pub fn close_work_verification(ctx: Context<CloseWorkVerification>) -> Result<()> {
+        instructions::close_work_verification(ctx)
+    }
+
+    pub fn close_application(ctx: Context<CloseApplication>) -> Result<()> {
+        instructions::close_application(ctx)
+    }
+
+    pub fn close_assignment(ctx: Context<CloseAssignment>) -> Result<()> {
+        instructions::close_assignment(ctx)
+    }
+
+    pub fn respond_to_review(
+        ctx: Context<RespondToReview>,
+        response_uri: String
+    ) -> Result<()> {
+        instructions::respond_to_review(ctx, response_uri)
+    }
+
+    pub fn moderate_review(
+        ctx: Context<ModerateReview>,
+        reason: String
+    ) -> Result<()> {
+        instructions::moderate_review(ctx, reason)
+    }
+
+    pub fn set_reputation_half_life(
+        ctx: Context<SetReputationHalfLife>,
+        half_life: i64
+    ) -> Result<()> {
+        instructions::set_reputation_half_life(ctx, half_life)
+    }
+
+    pub fn refresh_weighted_rating(
+        ctx: Context<RefreshWeightedRating>
+    ) -> Result<()> {
+        instructions::refresh_weighted_rating(ctx)
+    }
+
+    pub fn add_skill(
+        ctx: Context<AddSkill>,
+        name: String
+    ) -> Result<()> {
+        instructions::add_skill(ctx, name)
+    }
+
+    pub fn retire_skill(ctx: Context<RetireSkill>) -> Result<()> {
+        instructions::retire_skill(ctx)
+    }
+
+    pub fn create_skill_profile(ctx: Context<CreateSkillProfile>) -> Result<()> {
+        instructions::create_skill_profile(ctx)
+    }
+
+    pub fn add_user_skill(ctx: Context<ModifyUserSkill>) -> Result<()> {
+        instructions::add_user_skill(ctx)
+    }
+
+    pub fn remove_user_skill(ctx: Context<ModifyUserSkill>) -> Result<()> {
+        instructions::remove_user_skill(ctx)
+    }
+
+    pub fn endorse_skill(
+        ctx: Context<EndorseSkill>,
+        skill_id: u16
+    ) -> Result<()> {
+        instructions::endorse_skill(ctx, skill_id)
+    }
+
+    pub fn add_certification_type(
+        ctx: Context<AddCertificationType>,
+        name: String
+    ) -> Result<()> {
+        instructions::add_certification_type(ctx, name)
+    }
+
+    pub fn retire_certification_type(ctx: Context<RetireCertificationType>) -> Result<()> {
+        instructions::retire_certification_type(ctx)
+    }
+
+    pub fn issue_certification(
+        ctx: Context<IssueCertification>,
+        certification_type: u16,
+        evidence_uri: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::issue_certification(ctx, certification_type, evidence_uri, expires_at)
+    }
+
+    pub fn revoke_certification(
+        ctx: Context<ModifyCertification>,
+        reason: String
+    ) -> Result<()> {
+        instructions::revoke_certification(ctx, reason)
+    }
+
+    pub fn renew_certification(
+        ctx: Context<ModifyCertification>,
+        evidence_uri: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::renew_certification(ctx, evidence_uri, expires_at)
+    }
+
+    pub fn set_required_certifications<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetRequiredCertifications<'info>>,
+        certification_types: Vec<u16>,
+    ) -> Result<()> {
+        instructions::set_required_certifications(ctx, certification_types)
+    }
+
+    pub fn close_dispute(ctx: Context<CloseDispute>) -> Result<()> {
+        instructions::close_dispute(ctx)
+    }
+
+    pub fn amend_project_native(
+        ctx: Context<AmendProjectNative>,
+        deposit_amount: u64,
+        duration_days: Option<u16>,
+        daily_rate: Option<u64>,
+    ) -> Result<()> {
+        instructions::amend_project_native(ctx, deposit_amount, duration_days, daily_rate)
+    }
+
+    pub fn create_native_milestone_project(
+        ctx: Context<CreateNativeMilestoneProject>,
+        title: String,
+        metadata_uri: String,
+        max_labourers: u8,
+    ) -> Result<()> {
+        instructions::create_native_milestone_project(ctx, title, metadata_uri, max_labourers)
+    }
+
+    pub fn add_milestone_native(
+        ctx: Context<AddMilestoneNative>,
+        amount: u64,
+        description_uri: String,
+    ) -> Result<()> {
+        instructions::add_milestone_native(ctx, amount, description_uri)
+    }
+
+    pub fn approve_milestone_native(ctx: Context<ApproveMilestoneNative>) -> Result<()> {
+        instructions::approve_milestone_native(ctx)
+    }
 }
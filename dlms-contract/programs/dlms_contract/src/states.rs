@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::{DECAY_WEIGHT_SCALE, HOURS_PER_DAY, RATING_SCALE, SECONDS_PER_DAY};
+use crate::error::ErrorCode;
+use crate::utils::{decay, protocol_fee};
 
 #[account]
 #[derive(InitSpace)]
@@ -10,6 +13,22 @@ pub struct SystemState {
     pub project_count: u32,
     #[max_len(10)]
     pub admins: Vec<Pubkey>,
+    pub approval_window: i64,
+    pub fee_bps: u16,
+    #[max_len(10)]
+    pub accepted_mints: Vec<Pubkey>,
+    pub reputation_half_life: i64,
+    pub skill_count: u16,
+    pub certification_type_count: u16,
+}
+
+impl SystemState {
+    // Projects snapshot the fee at creation, so a charged fee needs its treasury before any project can owe it
+    pub fn require_treasury<T>(&self, treasury: Option<&T>) -> Result<()> {
+        require!(self.fee_bps == 0 || treasury.is_some(), ErrorCode::TreasuryRequired);
+
+        Ok(())
+    }
 }
 
 #[account]
@@ -24,12 +43,81 @@ pub struct UserAccount {
     pub verified: bool,
     pub rating: u32,
     pub rating_count: u32,
+    pub reputation: Reputation,
+    pub weighted_rating: u32,
+    pub weighted_rating_at: i64,
+    // Review score totals and weights decayed up to `weighted_rating_at`, scaled by DECAY_WEIGHT_SCALE
+    pub decayed_score_sum: u128,
+    pub decayed_weight: u128,
     pub timestamp: i64,
     pub index: u32,
     pub role: UserRole,
     pub spam: bool,
 }
 
+impl UserAccount {
+    // Recomputes the overall fixed-point rating from the reputation sums
+    pub fn refresh_rating(&mut self) -> Option<()> {
+        let score_count = (self.rating_count as u64).checked_mul(ReviewScores::DIMENSIONS as u64)?;
+        self.rating = Reputation::average(self.reputation.total()?, score_count)?;
+
+        Some(())
+    }
+
+    // Brings the decayed review sums forward to `now` and recomputes the weighted rating
+    pub fn apply_decay(&mut self, now: i64, half_life: i64) -> Option<()> {
+        let elapsed = now.saturating_sub(self.weighted_rating_at);
+        self.decayed_score_sum = decay(self.decayed_score_sum, elapsed, half_life)?;
+        self.decayed_weight = decay(self.decayed_weight, elapsed, half_life)?;
+        self.weighted_rating_at = now;
+
+        self.refresh_weighted_rating()
+    }
+
+    // Adds a review weighing `weight` to the decayed sums, they must be decayed up to now
+    pub fn add_weighted_review(&mut self, scores: &ReviewScores, weight: u128) -> Option<()> {
+        self.decayed_score_sum = self
+            .decayed_score_sum
+            .checked_add(weight.checked_mul(scores.total() as u128)?)?;
+        self.decayed_weight = self.decayed_weight.checked_add(weight)?;
+
+        self.refresh_weighted_rating()
+    }
+
+    // Takes a review weighing `weight` out of the decayed sums, they must be decayed up to now
+    pub fn remove_weighted_review(&mut self, scores: &ReviewScores, weight: u128) -> Option<()> {
+        if self.rating_count == 0 {
+            // Nothing is left, drop whatever the rounding of earlier decays left behind
+            self.decayed_score_sum = 0;
+            self.decayed_weight = 0;
+        } else {
+            // The sums were decayed in steps, so they can round below the review's own weight
+            self.decayed_score_sum = self
+                .decayed_score_sum
+                .saturating_sub(weight.checked_mul(scores.total() as u128)?);
+            self.decayed_weight = self.decayed_weight.saturating_sub(weight);
+        }
+
+        self.refresh_weighted_rating()
+    }
+
+    fn refresh_weighted_rating(&mut self) -> Option<()> {
+        // Reviews too old to carry any weight all weigh the same, which is the plain average
+        if self.decayed_weight == 0 {
+            self.weighted_rating = self.rating;
+            return Some(());
+        }
+
+        let weighted_rating = self
+            .decayed_score_sum
+            .checked_mul(RATING_SCALE as u128)?
+            .checked_div(self.decayed_weight.checked_mul(ReviewScores::DIMENSIONS as u128)?)?;
+        self.weighted_rating = u32::try_from(weighted_rating).ok()?;
+
+        Some(())
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Project {
@@ -44,8 +132,302 @@ pub struct Project {
     pub labour_count: u8,
     pub status: ProjectStatus,
     pub escrow_account: Pubkey,
+    pub mint: Pubkey,
     pub timestamp: i64,
     pub index: u32,
+    pub pending_verifications: u16,
+    pub days_paid: u32,
+    pub payment_mode: PaymentMode,
+    pub milestone_count: u8,
+    pub milestone_total: u64,
+    pub milestone_paid: u64,
+    pub fee_bps: u16,
+    pub escrow_asset: EscrowAsset,
+    pub streamed_paid: u64,
+    pub pending_milestone_total: u64,
+    pub active_streams: u8,
+    pub stream_owed: u64,
+    #[max_len(5)]
+    pub required_certifications: Vec<u16>,
+    pub cancellation_fee: u64,
+    pub daily_labourers: u8,
+    pub claimed_days: u32,
+    pub stream_committed: u64,
+}
+
+impl Project {
+    pub fn start(&mut self) -> Result<()> {
+        require!(self.status == ProjectStatus::Open, ErrorCode::ProjectNotOpen);
+
+        // Managers may start with fewer labourers than the maximum, but not with none
+        require!(self.labour_count > 0, ErrorCode::NoLabourers);
+
+        self.status = ProjectStatus::InProgress;
+
+        Ok(())
+    }
+
+    pub fn complete(&mut self) -> Result<()> {
+        require!(self.status == ProjectStatus::InProgress, ErrorCode::WrongProjectStatus);
+
+        // Every verified work day must be paid, rejected or ruled on first
+        require!(self.pending_verifications == 0, ErrorCode::UnsettledWorkDays);
+        require!(self.pending_milestone_total == 0, ErrorCode::UnsettledMilestones);
+
+        // Streams must be stopped and what they owe withdrawn by the labour
+        require!(self.active_streams == 0, ErrorCode::StreamsStillRunning);
+        require!(self.stream_owed == 0, ErrorCode::StreamNotSettled);
+
+        self.status = ProjectStatus::Completed;
+
+        Ok(())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.status == ProjectStatus::Completed || self.status == ProjectStatus::Cancelled
+    }
+
+    // Open and in progress projects are cancelled by a close, only a project that went through
+    // `complete` can be closed as completed. A closed project can be closed again with the same
+    // status to refund what is no longer held back.
+    pub fn finish(&mut self, status: ProjectStatus) -> Result<()> {
+        match status {
+            ProjectStatus::Completed => {
+                require!(self.status == ProjectStatus::Completed, ErrorCode::WrongProjectStatus);
+            }
+            ProjectStatus::Cancelled => {
+                require!(self.status != ProjectStatus::Completed, ErrorCode::ProjectAlreadyClosed);
+            }
+            _ => return err!(ErrorCode::WrongProjectStatus),
+        }
+
+        self.status = status;
+
+        Ok(())
+    }
+
+    // Duration can only be extended and the rate only raised, the new rate applies to future days.
+    // Days already recorded and streams already started keep the rate they were worked at, so it
+    // cannot change while any of them is unsettled.
+    pub fn amend(&mut self, duration_days: Option<u16>, daily_rate: Option<u64>) -> Result<()> {
+        require!(
+            self.status == ProjectStatus::Open || self.status == ProjectStatus::InProgress,
+            ErrorCode::ProjectNotActive
+        );
+
+        if let Some(duration_days) = duration_days {
+            require!(duration_days >= self.duration_days, ErrorCode::InvalidDuration);
+            self.duration_days = duration_days;
+        }
+
+        if let Some(daily_rate) = daily_rate {
+            require!(daily_rate >= self.daily_rate, ErrorCode::InvalidDailyRate);
+            require!(
+                daily_rate == self.daily_rate || self.active_streams == 0,
+                ErrorCode::StreamsStillRunning
+            );
+            require!(
+                daily_rate == self.daily_rate || self.pending_verifications == 0,
+                ErrorCode::UnsettledWorkDays
+            );
+            self.daily_rate = daily_rate;
+        }
+
+        Ok(())
+    }
+
+    // The escrow must cover everything still owed, including what streams may still draw
+    pub fn require_funded(&self, escrow_balance: u64) -> Result<()> {
+        let outstanding = self
+            .outstanding_obligation()
+            .ok_or(ErrorCode::CalculationError)?;
+
+        msg!(
+            "Escrow balance: {}, outstanding obligation: {}",
+            escrow_balance,
+            outstanding
+        );
+
+        require!(escrow_balance >= outstanding, ErrorCode::InsufficientFunds);
+
+        Ok(())
+    }
+
+    // Wages and protocol fees still owed from escrow for every labour day or milestone not paid yet
+    pub fn outstanding_obligation(&self) -> Option<u64> {
+        let wages = match self.payment_mode {
+            PaymentMode::DailyRate => {
+                // Slots left open can still be filled by a hire working the whole duration
+                let open_slots = if self.status == ProjectStatus::Open {
+                    self.max_labourers.checked_sub(self.labour_count)?
+                } else {
+                    0
+                };
+
+                // Every active labour may still claim the rest of the duration, days already
+                // claimed are owed only while they wait on settlement
+                let unclaimed_days = (self.daily_labourers as u32)
+                    .checked_add(open_slots as u32)?
+                    .checked_mul(self.duration_days as u32)?
+                    .checked_sub(self.claimed_days)?;
+                let owed_days = unclaimed_days.checked_add(self.pending_verifications as u32)?;
+
+                self.daily_rate
+                    .checked_mul(owed_days as u64)?
+                    .checked_add(self.stream_committed)?
+                    .checked_add(self.stream_owed)?
+            }
+            PaymentMode::Milestone => self.milestone_total.checked_sub(self.milestone_paid)?,
+        };
+
+        wages.checked_add(protocol_fee(wages, self.fee_bps)?)
+    }
+
+    // Funds that must stay in escrow on close for work that was claimed but not paid yet
+    pub fn settlement_holdback(&self) -> Option<u64> {
+        let wages = match self.payment_mode {
+            // Labour verified days are held back at the full daily rate, the most a day can pay
+            PaymentMode::DailyRate => self
+                .daily_rate
+                .checked_mul(self.pending_verifications as u64)?
+                .checked_add(self.stream_owed)?,
+            PaymentMode::Milestone => self.pending_milestone_total,
+        };
+
+        // Cancellation fees are still owed to every labour the project was cancelled on
+        let cancellation_fees = self
+            .cancellation_fee
+            .checked_mul(self.labour_count as u64)?;
+
+        wages
+            .checked_add(protocol_fee(wages, self.fee_bps)?)?
+            .checked_add(cancellation_fees)
+    }
+
+    pub fn add_daily_labourer(&mut self) -> Option<()> {
+        self.daily_labourers = self.daily_labourers.checked_add(1)?;
+
+        Some(())
+    }
+
+    // The days the assignment never claimed are no longer owed, claimed ones stay pending until settled
+    pub fn release_daily_labourer(&mut self, assignment: &Assignment) -> Option<()> {
+        self.daily_labourers = self.daily_labourers.checked_sub(1)?;
+        self.claimed_days = self.claimed_days.checked_sub(assignment.days_claimed as u32)?;
+
+        Some(())
+    }
+
+    // A day recorded by either side uses up one day of the duration and waits on settlement
+    pub fn claim_day(&mut self, assignment: &mut Assignment) -> Option<()> {
+        assignment.days_claimed = assignment.days_claimed.checked_add(1)?;
+        self.claimed_days = self.claimed_days.checked_add(1)?;
+        self.pending_verifications = self.pending_verifications.checked_add(1)?;
+
+        Some(())
+    }
+
+    // A started stream is owed up to its cap instead of the days of the assignment
+    pub fn commit_stream(&mut self, assignment: &Assignment, stream: &Stream) -> Option<()> {
+        self.release_daily_labourer(assignment)?;
+        self.active_streams = self.active_streams.checked_add(1)?;
+        self.stream_committed = self.stream_committed.checked_add(stream.cap)?;
+
+        Some(())
+    }
+
+    // Only what a stopped stream accrued stays owed, the rest of its cap is released
+    pub fn release_stream(&mut self, stream: &Stream) -> Option<()> {
+        let uncommitted = stream.cap.checked_sub(stream.withdrawn)?;
+        let unpaid = stream.accrued.checked_sub(stream.withdrawn)?;
+
+        self.active_streams = self.active_streams.checked_sub(1)?;
+        self.stream_committed = self.stream_committed.checked_sub(uncommitted)?;
+        self.stream_owed = self.stream_owed.checked_add(unpaid)?;
+
+        Some(())
+    }
+
+    // Daily rate prorated to the hours worked, a full day pays exactly `daily_rate`
+    pub fn wage_for(&self, hours_worked: u8) -> Option<u64> {
+        let wage = (self.daily_rate as u128)
+            .checked_mul(hours_worked as u128)?
+            .checked_div(HOURS_PER_DAY as u128)?;
+
+        u64::try_from(wage).ok()
+    }
+}
+
+// Per dimension scores of a single review, each from 1 to 5
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ReviewScores {
+    pub quality: u8,
+    pub punctuality: u8,
+    pub safety: u8,
+    pub communication: u8,
+}
+
+impl ReviewScores {
+    pub const DIMENSIONS: u32 = 4;
+
+    pub fn is_valid(&self) -> bool {
+        [self.quality, self.punctuality, self.safety, self.communication]
+            .iter()
+            .all(|score| (1..=5).contains(score))
+    }
+
+    pub fn total(&self) -> u32 {
+        self.quality as u32 + self.punctuality as u32 + self.safety as u32 + self.communication as u32
+    }
+}
+
+// Running score sums per dimension, averages are derived with `average`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Default)]
+pub struct Reputation {
+    pub quality: u64,
+    pub punctuality: u64,
+    pub safety: u64,
+    pub communication: u64,
+}
+
+impl Reputation {
+    pub fn add(&mut self, scores: &ReviewScores) -> Option<()> {
+        self.quality = self.quality.checked_add(scores.quality as u64)?;
+        self.punctuality = self.punctuality.checked_add(scores.punctuality as u64)?;
+        self.safety = self.safety.checked_add(scores.safety as u64)?;
+        self.communication = self.communication.checked_add(scores.communication as u64)?;
+
+        Some(())
+    }
+
+    pub fn remove(&mut self, scores: &ReviewScores) -> Option<()> {
+        self.quality = self.quality.checked_sub(scores.quality as u64)?;
+        self.punctuality = self.punctuality.checked_sub(scores.punctuality as u64)?;
+        self.safety = self.safety.checked_sub(scores.safety as u64)?;
+        self.communication = self.communication.checked_sub(scores.communication as u64)?;
+
+        Some(())
+    }
+
+    pub fn total(&self) -> Option<u64> {
+        self.quality
+            .checked_add(self.punctuality)?
+            .checked_add(self.safety)?
+            .checked_add(self.communication)
+    }
+
+    // Fixed-point average of `sum` over `count` scores, scaled by RATING_SCALE
+    pub fn average(sum: u64, count: u64) -> Option<u32> {
+        if count == 0 {
+            return Some(0);
+        }
+
+        let average = (sum as u128)
+            .checked_mul(RATING_SCALE as u128)?
+            .checked_div(count as u128)?;
+
+        u32::try_from(average).ok()
+    }
 }
 
 #[account]
@@ -53,11 +435,25 @@ pub struct Project {
 pub struct Review {
     pub reviewer: Pubkey,
     pub reviewee: Pubkey,
-    pub rating: u8,
+    pub project: Pubkey,
+    pub scores: ReviewScores,
     #[max_len(250)]
     pub context: String,
     pub timestamp: i64,
     pub review_type: ReviewType,
+    #[max_len(250)]
+    pub response_uri: String,
+    pub hidden: bool,
+    #[max_len(200)]
+    pub moderation_reason: String,
+    pub moderated_by: Option<Pubkey>,
+}
+
+impl Review {
+    // Weight of the review at `now`, scaled by DECAY_WEIGHT_SCALE and halving every `half_life` seconds
+    pub fn decay_weight(&self, now: i64, half_life: i64) -> Option<u128> {
+        decay(DECAY_WEIGHT_SCALE, now.saturating_sub(self.timestamp), half_life)
+    }
 }
 
 #[account]
@@ -80,6 +476,14 @@ pub struct Assignment {
     pub days_paid: u16,
     pub active: bool,
     pub timestamp: i64,
+    pub ended_at: i64,
+    pub termination_reason: Option<TerminationReason>,
+    pub resigned: bool,
+    pub bonus_count: u16,
+    pub bonus_total: u64,
+    pub streaming: bool,
+    pub cancellation_fee_paid: bool,
+    pub days_claimed: u16,
 }
 
 #[account]
@@ -88,12 +492,190 @@ pub struct WorkVerification {
     pub project: Pubkey,
     pub labour: Pubkey,
     pub day_number: u16,
+    pub hours_worked: u8,
     pub manager_verified: bool,
     pub labour_verified: bool,
     #[max_len(250)]
     pub metadata_uri: String,
     pub timestamp: i64,
     pub payment_processed: bool,
+    pub disputed: bool,
+    pub rejected: bool,
+    pub rent_payer: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Milestone {
+    pub project: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    #[max_len(250)]
+    pub description_uri: String,
+    pub assignee: Option<Pubkey>,
+    #[max_len(250)]
+    pub submission_uri: String,
+    pub labour_verified: bool,
+    pub manager_verified: bool,
+    pub payment_processed: bool,
+    pub timestamp: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    pub assignment: Pubkey,
+    pub project: Pubkey,
+    pub labour: Pubkey,
+    pub rate_per_day: u64,
+    pub cap: u64,
+    pub accrued: u64,
+    pub withdrawn: u64,
+    pub last_checkpoint: i64,
+    pub paused: bool,
+    pub stopped: bool,
+    pub timestamp: i64,
+}
+
+impl Stream {
+    // Wages accrued by `now`, nothing accrues while paused or after the stream is stopped
+    pub fn accrued_at(&self, now: i64) -> Option<u64> {
+        if self.paused || self.stopped {
+            return Some(self.accrued);
+        }
+
+        let elapsed = now.saturating_sub(self.last_checkpoint).max(0) as u128;
+        let streamed = (self.rate_per_day as u128)
+            .checked_mul(elapsed)?
+            .checked_div(SECONDS_PER_DAY as u128)?;
+        let accrued = (self.accrued as u128)
+            .checked_add(streamed)?
+            .min(self.cap as u128);
+
+        u64::try_from(accrued).ok()
+    }
+
+    // Folds the accrual up to `now` into `accrued` so the rate can be paused or stopped
+    pub fn checkpoint(&mut self, now: i64) -> Option<()> {
+        self.accrued = self.accrued_at(now)?;
+        self.last_checkpoint = now;
+
+        Some(())
+    }
+}
+
+// Skill tag from the admin curated taxonomy
+#[account]
+#[derive(InitSpace)]
+pub struct Skill {
+    pub id: u16,
+    #[max_len(50)]
+    pub name: String,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SkillProfile {
+    pub user: Pubkey,
+    #[max_len(20)]
+    pub skills: Vec<UserSkill>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct UserSkill {
+    pub skill_id: u16,
+    pub endorsements: u32,
+}
+
+// One per manager and skill, stops the same manager endorsing a skill twice
+#[account]
+#[derive(InitSpace)]
+pub struct Endorsement {
+    pub skill_profile: Pubkey,
+    pub skill_id: u16,
+    pub endorser: Pubkey,
+    pub project: Pubkey,
+    pub timestamp: i64,
+}
+
+// Certification type from the admin curated registry
+#[account]
+#[derive(InitSpace)]
+pub struct CertificationType {
+    pub id: u16,
+    #[max_len(50)]
+    pub name: String,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+// Issued by an admin, one per user and certification type
+#[account]
+#[derive(InitSpace)]
+pub struct Certification {
+    pub user: Pubkey,
+    pub certification_type: u16,
+    pub issuer: Pubkey,
+    #[max_len(250)]
+    pub evidence_uri: String,
+    pub issued_at: i64,
+    pub expires_at: i64,
+    pub revoked: bool,
+    pub revoked_at: i64,
+}
+
+impl Certification {
+    pub fn is_valid(&self, now: i64) -> bool {
+        !self.revoked && self.expires_at > now
+    }
+}
+
+// Lamport escrow for native SOL projects, owned by the program so it can pay out directly
+#[account]
+#[derive(InitSpace)]
+pub struct NativeEscrow {
+    pub project: Pubkey,
+}
+
+// Lamport treasury collecting the protocol fees of native SOL projects
+#[account]
+#[derive(InitSpace)]
+pub struct NativeTreasury {
+    pub system_state: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Bonus {
+    pub assignment: Pubkey,
+    pub project: Pubkey,
+    pub labour: Pubkey,
+    pub amount: u64,
+    #[max_len(250)]
+    pub memo_uri: String,
+    pub source: BonusSource,
+    pub timestamp: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub work_verification: Pubkey,
+    pub project: Pubkey,
+    pub labour: Pubkey,
+    pub opened_by: Pubkey,
+    #[max_len(250)]
+    pub labour_evidence_uri: String,
+    #[max_len(250)]
+    pub manager_evidence_uri: String,
+    pub status: DisputeStatus,
+    pub ruling: Option<DisputeRuling>,
+    pub resolved_by: Option<Pubkey>,
+    pub timestamp: i64,
+    pub resolved_at: i64,
+    pub rent_payer: Pubkey,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq)]
@@ -110,6 +692,18 @@ pub enum ProjectStatus {
     Cancelled,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq)]
+pub enum PaymentMode {
+    DailyRate,
+    Milestone,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace, Eq)]
+pub enum EscrowAsset {
+    Token,
+    Native,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace, Eq)]
 pub enum ApplicationStatus {
     Pending,
@@ -118,6 +712,34 @@ pub enum ApplicationStatus {
     Withdrawn,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq)]
+pub enum DisputeStatus {
+    Open,
+    Resolved,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq)]
+pub enum DisputeRuling {
+    PayLabour,
+    RejectDay,
+    Split,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq)]
+pub enum BonusSource {
+    EscrowSurplus,
+    ManagerAccount,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq)]
+pub enum TerminationReason {
+    Misconduct,
+    NoShow,
+    Performance,
+    ScaleDown,
+    Other,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq)]
 pub enum ReviewType {
     LabourReview,
@@ -129,3 +751,496 @@ pub enum AccountType {
     Labour,
     Manager,
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::constants::{DEFAULT_APPROVAL_WINDOW, DEFAULT_REPUTATION_HALF_LIFE};
+
+    pub(crate) fn system_state(authority: Pubkey) -> SystemState {
+        SystemState {
+            authority,
+            mint: Pubkey::default(),
+            labour_count: 0,
+            manager_count: 0,
+            project_count: 0,
+            admins: vec![authority],
+            approval_window: DEFAULT_APPROVAL_WINDOW,
+            fee_bps: 0,
+            accepted_mints: Vec::new(),
+            reputation_half_life: DEFAULT_REPUTATION_HALF_LIFE,
+            skill_count: 0,
+            certification_type_count: 0,
+        }
+    }
+
+    pub(crate) fn user_account(authority: Pubkey, role: UserRole) -> UserAccount {
+        UserAccount {
+            authority,
+            name: String::new(),
+            metadata_uri: String::new(),
+            active: true,
+            verified: false,
+            rating: 0,
+            rating_count: 0,
+            reputation: Reputation::default(),
+            weighted_rating: 0,
+            weighted_rating_at: 0,
+            decayed_score_sum: 0,
+            decayed_weight: 0,
+            timestamp: 0,
+            index: 0,
+            role,
+            spam: false,
+        }
+    }
+
+    pub(crate) fn daily_rate_project() -> Project {
+        Project {
+            manager: Pubkey::default(),
+            title: String::new(),
+            metadata_uri: String::new(),
+            daily_rate: 100,
+            duration_days: 10,
+            max_labourers: 2,
+            labour_count: 2,
+            status: ProjectStatus::InProgress,
+            escrow_account: Pubkey::default(),
+            mint: Pubkey::default(),
+            timestamp: 0,
+            index: 0,
+            pending_verifications: 0,
+            days_paid: 0,
+            payment_mode: PaymentMode::DailyRate,
+            milestone_count: 0,
+            milestone_total: 0,
+            milestone_paid: 0,
+            fee_bps: 100,
+            escrow_asset: EscrowAsset::Token,
+            streamed_paid: 0,
+            pending_milestone_total: 0,
+            active_streams: 0,
+            stream_owed: 0,
+            required_certifications: Vec::new(),
+            cancellation_fee: 0,
+            daily_labourers: 2,
+            claimed_days: 0,
+            stream_committed: 0,
+        }
+    }
+
+    pub(crate) fn assignment(days_claimed: u16) -> Assignment {
+        Assignment {
+            labour: Pubkey::default(),
+            project: Pubkey::default(),
+            days_worked: days_claimed,
+            days_paid: days_claimed,
+            active: true,
+            timestamp: 0,
+            ended_at: 0,
+            termination_reason: None,
+            resigned: false,
+            bonus_count: 0,
+            bonus_total: 0,
+            streaming: false,
+            cancellation_fee_paid: false,
+            days_claimed,
+        }
+    }
+
+    pub(crate) fn stream(rate_per_day: u64, cap: u64) -> Stream {
+        Stream {
+            assignment: Pubkey::default(),
+            project: Pubkey::default(),
+            labour: Pubkey::default(),
+            rate_per_day,
+            cap,
+            accrued: 0,
+            withdrawn: 0,
+            last_checkpoint: 0,
+            paused: false,
+            stopped: false,
+            timestamp: 0,
+        }
+    }
+
+    pub(crate) fn certification(user: Pubkey, certification_type: u16, expires_at: i64) -> Certification {
+        Certification {
+            user,
+            certification_type,
+            issuer: Pubkey::default(),
+            evidence_uri: String::new(),
+            issued_at: 0,
+            expires_at,
+            revoked: false,
+            revoked_at: 0,
+        }
+    }
+
+    pub(crate) fn scores(score: u8) -> ReviewScores {
+        ReviewScores {
+            quality: score,
+            punctuality: score,
+            safety: score,
+            communication: score,
+        }
+    }
+
+    pub(crate) fn review_at(timestamp: i64) -> Review {
+        Review {
+            reviewer: Pubkey::default(),
+            reviewee: Pubkey::default(),
+            project: Pubkey::default(),
+            scores: scores(5),
+            context: String::new(),
+            timestamp,
+            review_type: ReviewType::LabourReview,
+            response_uri: String::new(),
+            hidden: false,
+            moderation_reason: String::new(),
+            moderated_by: None,
+        }
+    }
+
+    #[test]
+    fn charged_fee_requires_a_treasury() {
+        let mut system_state = system_state(Pubkey::default());
+        let treasury = Pubkey::default();
+
+        assert!(system_state.require_treasury::<Pubkey>(None).is_ok());
+
+        system_state.fee_bps = 100;
+        assert!(system_state.require_treasury::<Pubkey>(None).is_err());
+        assert!(system_state.require_treasury(Some(&treasury)).is_ok());
+    }
+
+    #[test]
+    fn holdback_includes_protocol_fee() {
+        let mut project = daily_rate_project();
+        project.pending_verifications = 3;
+
+        // 3 days at 100 plus the 1% fee
+        assert_eq!(project.settlement_holdback(), Some(303));
+    }
+
+    #[test]
+    fn holdback_includes_stream_owed() {
+        let mut project = daily_rate_project();
+        project.pending_verifications = 1;
+        project.stream_owed = 50;
+
+        // The fee on 150 rounds down to 1
+        assert_eq!(project.settlement_holdback(), Some(151));
+    }
+
+    #[test]
+    fn holdback_includes_unpaid_cancellation_fees() {
+        let mut project = daily_rate_project();
+        project.cancellation_fee = 10;
+
+        // Cancellation fees carry no protocol fee
+        assert_eq!(project.settlement_holdback(), Some(20));
+
+        project.labour_count = 1;
+        assert_eq!(project.settlement_holdback(), Some(10));
+    }
+
+    #[test]
+    fn holdback_of_milestone_project() {
+        let mut project = daily_rate_project();
+        project.payment_mode = PaymentMode::Milestone;
+        project.pending_verifications = 3;
+        project.pending_milestone_total = 500;
+
+        assert_eq!(project.settlement_holdback(), Some(505));
+    }
+
+    #[test]
+    fn completed_project_can_be_closed() {
+        let mut project = daily_rate_project();
+        project.status = ProjectStatus::Open;
+
+        assert!(project.start().is_ok());
+        assert!(project.complete().is_ok());
+        assert!(project.finish(ProjectStatus::Completed).is_ok());
+        assert!(project.status == ProjectStatus::Completed);
+
+        // A completed project can be closed again but never cancelled
+        assert!(project.finish(ProjectStatus::Completed).is_ok());
+        assert!(project.finish(ProjectStatus::Cancelled).is_err());
+    }
+
+    #[test]
+    fn project_must_be_completed_before_closing_as_completed() {
+        let mut project = daily_rate_project();
+        project.pending_verifications = 1;
+
+        // Closing cannot skip the settlement checks of `complete`
+        assert!(project.finish(ProjectStatus::Completed).is_err());
+        assert!(project.status == ProjectStatus::InProgress);
+    }
+
+    #[test]
+    fn cancelled_project_can_only_be_closed_as_cancelled() {
+        let mut project = daily_rate_project();
+
+        assert!(project.finish(ProjectStatus::Cancelled).is_ok());
+        assert!(project.finish(ProjectStatus::Cancelled).is_ok());
+        assert!(project.finish(ProjectStatus::Completed).is_err());
+        assert!(project.status == ProjectStatus::Cancelled);
+    }
+
+    #[test]
+    fn project_cannot_complete_with_unsettled_days() {
+        let mut project = daily_rate_project();
+        project.pending_verifications = 1;
+
+        assert!(project.complete().is_err());
+        assert!(project.finish(ProjectStatus::InProgress).is_err());
+    }
+
+    #[test]
+    fn project_cannot_complete_with_unsettled_streams_or_milestones() {
+        let mut project = daily_rate_project();
+        project.active_streams = 1;
+        assert!(project.complete().is_err());
+
+        project.active_streams = 0;
+        project.stream_owed = 50;
+        assert!(project.complete().is_err());
+
+        project.stream_owed = 0;
+        project.pending_milestone_total = 500;
+        assert!(project.complete().is_err());
+
+        project.pending_milestone_total = 0;
+        assert!(project.complete().is_ok());
+    }
+
+    #[test]
+    fn amend_only_extends_and_raises() {
+        let mut project = daily_rate_project();
+        assert!(project.amend(Some(9), None).is_err());
+        assert!(project.amend(None, Some(99)).is_err());
+
+        project.active_streams = 1;
+        assert!(project.amend(None, Some(150)).is_err());
+        assert!(project.amend(Some(12), Some(100)).is_ok());
+
+        assert!(project.require_funded(2_000).is_err());
+        assert!(project.require_funded(2_424).is_ok());
+    }
+
+    #[test]
+    fn rate_cannot_change_while_a_day_is_pending() {
+        let mut project = daily_rate_project();
+        let mut worker = assignment(0);
+        project.claim_day(&mut worker).unwrap();
+
+        // The recorded day was worked at 100 and is held back at that rate
+        assert!(project.amend(None, Some(150)).is_err());
+        assert!(project.amend(Some(12), None).is_ok());
+        assert_eq!(project.settlement_holdback(), Some(101));
+
+        let mut work_verification = WorkVerification {
+            project: Pubkey::default(),
+            labour: Pubkey::default(),
+            day_number: 1,
+            hours_worked: HOURS_PER_DAY,
+            manager_verified: true,
+            labour_verified: true,
+            metadata_uri: String::new(),
+            timestamp: 0,
+            payment_processed: false,
+            disputed: false,
+            rejected: false,
+            rent_payer: Pubkey::default(),
+        };
+        crate::utils::record_work_day(&mut work_verification, &mut worker, &mut project, true).unwrap();
+
+        assert!(project.amend(None, Some(150)).is_ok());
+        assert_eq!(project.wage_for(HOURS_PER_DAY), Some(150));
+    }
+
+    #[test]
+    fn outstanding_obligation_of_daily_rate_project() {
+        let mut project = daily_rate_project();
+        assert_eq!(project.outstanding_obligation(), Some(2020));
+
+        // 5 days claimed, one of them still waiting on settlement
+        let mut worker = assignment(4);
+        project.claimed_days = 4;
+        project.claim_day(&mut worker).unwrap();
+        assert_eq!(project.outstanding_obligation(), Some(1616));
+
+        // A stream is owed up to its cap on top of the days
+        project.stream_committed = 200;
+        project.stream_owed = 50;
+        assert_eq!(project.outstanding_obligation(), Some(1868));
+    }
+
+    #[test]
+    fn departed_labour_is_not_owed_its_unclaimed_days() {
+        let mut project = daily_rate_project();
+        let departed = assignment(3);
+        project.claimed_days = 3;
+
+        project.release_daily_labourer(&departed).unwrap();
+        project.labour_count = 1;
+        assert_eq!(project.outstanding_obligation(), Some(1010));
+
+        // The reopened slot is owed in full to whoever replaces them, but the 3 days paid
+        // to the departed labour already left the escrow, so it must be topped up to hire
+        project.status = ProjectStatus::Open;
+        assert_eq!(project.outstanding_obligation(), Some(2020));
+        assert!(project.require_funded(1717).is_err());
+
+        project.add_daily_labourer().unwrap();
+        project.labour_count = 2;
+        project.status = ProjectStatus::InProgress;
+        assert!(project.require_funded(1717).is_err());
+        assert!(project.require_funded(2020).is_ok());
+    }
+
+    #[test]
+    fn outstanding_obligation_fails_on_underflow() {
+        let mut project = daily_rate_project();
+        project.claimed_days = 21;
+
+        assert_eq!(project.outstanding_obligation(), None);
+    }
+
+    #[test]
+    fn outstanding_obligation_of_milestone_project() {
+        let mut project = daily_rate_project();
+        project.payment_mode = PaymentMode::Milestone;
+        project.milestone_total = 1000;
+        project.milestone_paid = 400;
+
+        assert_eq!(project.outstanding_obligation(), Some(606));
+    }
+
+    #[test]
+    fn stopped_stream_releases_the_rest_of_its_cap() {
+        let mut project = daily_rate_project();
+        let mut streamed = stream(100, 1000);
+
+        project.commit_stream(&assignment(0), &streamed).unwrap();
+        assert_eq!(project.outstanding_obligation(), Some(2020));
+
+        streamed.accrued = 300;
+        streamed.withdrawn = 100;
+        project.stream_committed -= 100;
+        project.release_stream(&streamed).unwrap();
+        assert_eq!(project.active_streams, 0);
+        assert_eq!(project.stream_owed, 200);
+        assert_eq!(project.outstanding_obligation(), Some(1212));
+    }
+
+    #[test]
+    fn stream_accrues_per_second() {
+        let stream = stream(100, 1000);
+
+        assert_eq!(stream.accrued_at(SECONDS_PER_DAY / 2), Some(50));
+        assert_eq!(stream.accrued_at(SECONDS_PER_DAY * 3), Some(300));
+    }
+
+    #[test]
+    fn stream_is_capped() {
+        let mut stream = stream(100, 250);
+
+        assert_eq!(stream.accrued_at(SECONDS_PER_DAY * 5), Some(250));
+
+        stream.checkpoint(SECONDS_PER_DAY * 5).unwrap();
+        assert_eq!(stream.accrued, 250);
+        assert_eq!(stream.accrued_at(SECONDS_PER_DAY * 10), Some(250));
+    }
+
+    #[test]
+    fn paused_stream_does_not_accrue() {
+        let mut stream = stream(100, 1000);
+        stream.checkpoint(SECONDS_PER_DAY).unwrap();
+        stream.paused = true;
+
+        assert_eq!(stream.accrued_at(SECONDS_PER_DAY * 4), Some(100));
+    }
+
+    #[test]
+    fn review_scores_must_each_be_between_one_and_five() {
+        assert!(scores(1).is_valid());
+        assert!(scores(5).is_valid());
+        assert!(!scores(0).is_valid());
+        assert!(!scores(6).is_valid());
+
+        let mut mixed = scores(4);
+        mixed.safety = 0;
+        assert!(!mixed.is_valid());
+    }
+
+    #[test]
+    fn average_of_four_fives_and_one_four() {
+        let mut reputation = Reputation::default();
+        for _ in 0..4 {
+            reputation.add(&scores(5)).unwrap();
+        }
+        reputation.add(&scores(4)).unwrap();
+
+        let score_count = 5 * ReviewScores::DIMENSIONS as u64;
+        assert_eq!(
+            Reputation::average(reputation.total().unwrap(), score_count),
+            Some(480)
+        );
+    }
+
+    #[test]
+    fn average_without_reviews_is_zero() {
+        assert_eq!(Reputation::average(0, 0), Some(0));
+    }
+
+    #[test]
+    fn review_weight_decays() {
+        let half_life = 100;
+        let review = review_at(0);
+
+        assert_eq!(review.decay_weight(0, half_life), Some(DECAY_WEIGHT_SCALE));
+        assert_eq!(review.decay_weight(half_life, half_life), Some(DECAY_WEIGHT_SCALE / 2));
+        assert!(review.decay_weight(59 * half_life, half_life).unwrap() > 0);
+        assert_eq!(review.decay_weight(64 * half_life, half_life), Some(0));
+    }
+
+    #[test]
+    fn weighted_rating_favours_recent_reviews() {
+        let half_life = 100;
+        let mut user = UserAccount {
+            authority: Pubkey::default(),
+            name: String::new(),
+            metadata_uri: String::new(),
+            active: true,
+            verified: false,
+            rating: 0,
+            rating_count: 0,
+            reputation: Reputation::default(),
+            weighted_rating: 0,
+            weighted_rating_at: 0,
+            decayed_score_sum: 0,
+            decayed_weight: 0,
+            timestamp: 0,
+            index: 0,
+            role: UserRole::Labour,
+            spam: false,
+        };
+
+        user.add_weighted_review(&scores(5), DECAY_WEIGHT_SCALE).unwrap();
+        user.apply_decay(half_life, half_life).unwrap();
+        user.add_weighted_review(&scores(2), DECAY_WEIGHT_SCALE).unwrap();
+
+        // (5 * 0.5 + 2) / 1.5 is 3.00
+        assert_eq!(user.weighted_rating, 300);
+
+        // Long after, both reviews have rounded away and the plain average is reported
+        user.rating = 350;
+        user.apply_decay(200 * half_life, half_life).unwrap();
+        assert_eq!(user.decayed_weight, 0);
+        assert_eq!(user.weighted_rating, 350);
+    }
+}
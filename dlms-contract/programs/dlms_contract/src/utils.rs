@@ -0,0 +1,1603 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_interface::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::*;
+use crate::error::ErrorCode;
+use crate::states::{
+    Assignment, Bonus, BonusSource, Certification, Dispute, DisputeRuling, DisputeStatus,
+    EscrowAsset, Milestone, Project, ProjectStatus, Stream, TerminationReason, WorkVerification,
+};
+
+// Releases `amount` from the project escrow, signing with the project PDA
+pub fn transfer_from_escrow<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    escrow_account: &InterfaceAccount<'info, TokenAccount>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    project: &Account<'info, Project>,
+    project_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let project_index_bytes = project.index.to_le_bytes();
+    let project_seeds = &[
+        PROJECT_SEED.as_bytes(),
+        project.manager.as_ref(),
+        &project_index_bytes,
+        &[project_bump],
+    ];
+    let signer = &[&project_seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: escrow_account.to_account_info(),
+            mint: mint.to_account_info(),
+            to: destination.to_account_info(),
+            authority: project.to_account_info(),
+        },
+        signer,
+    );
+
+    token_interface::transfer_checked(transfer_ctx, amount, mint.decimals)
+}
+
+// Transfer fee the mint withholds when `amount` must arrive in full, zero for mints without one
+pub fn inverse_transfer_fee(mint: &InterfaceAccount<'_, Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+
+    match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => {
+            let fee = transfer_fee_config
+                .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+                .ok_or(ErrorCode::CalculationError)?;
+            Ok(fee)
+        }
+        Err(_) => Ok(0),
+    }
+}
+
+// Funds the escrow so that it receives at least `amount` after any transfer fee,
+// returns the amount actually credited to the escrow
+pub fn deposit_to_escrow<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    source: &InterfaceAccount<'info, TokenAccount>,
+    escrow_account: &mut InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &Signer<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let send_amount = amount
+        .checked_add(inverse_transfer_fee(mint, amount)?)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    require!(source.amount >= send_amount, ErrorCode::InsufficientFunds);
+
+    let balance_before = escrow_account.amount;
+
+    let cpi_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: source.to_account_info(),
+            mint: mint.to_account_info(),
+            to: escrow_account.to_account_info(),
+            authority: authority.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(cpi_ctx, send_amount, mint.decimals)?;
+
+    escrow_account.reload()?;
+    let received = escrow_account
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    require!(received >= amount, ErrorCode::InsufficientFunds);
+
+    Ok(received)
+}
+
+// What the project's escrow holds for wages, native vaults keep their rent exempt balance aside
+pub fn escrow_balance(project: &Project, escrow_info: &AccountInfo) -> Result<u64> {
+    match project.escrow_asset {
+        EscrowAsset::Token => {
+            let escrow_account =
+                TokenAccount::try_deserialize(&mut &escrow_info.try_borrow_data()?[..])?;
+
+            Ok(escrow_account.amount)
+        }
+        EscrowAsset::Native => {
+            let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+            let balance = escrow_info
+                .lamports()
+                .checked_sub(rent_exempt)
+                .ok_or(ErrorCode::InsufficientFunds)?;
+
+            Ok(balance)
+        }
+    }
+}
+
+// Decays `value` by 2^(-elapsed / half_life). Whole half-lives are exact halvings and within
+// a half-life the curve is approximated linearly between the two halvings.
+pub fn decay(value: u128, elapsed: i64, half_life: i64) -> Option<u128> {
+    if half_life <= 0 {
+        return None;
+    }
+
+    let elapsed = elapsed.max(0);
+    let half_lives = elapsed / half_life;
+    if half_lives >= u128::BITS as i64 {
+        return Some(0);
+    }
+
+    let base = value >> half_lives;
+    let remainder = (elapsed % half_life) as u128;
+    let period = 2 * half_life as u128;
+
+    // Split so the product cannot overflow however long the half-life is
+    let lost = (base / period) * remainder + (base % period) * remainder / period;
+
+    base.checked_sub(lost)
+}
+
+// Protocol fee charged on top of a wage, rounded down
+pub fn protocol_fee(amount: u64, fee_bps: u16) -> Option<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)?
+        .checked_div(BPS_DENOMINATOR as u128)?;
+
+    u64::try_from(fee).ok()
+}
+
+// Pays a wage to the labour and routes the project's protocol fee to the treasury
+#[allow(clippy::too_many_arguments)]
+pub fn pay_wage<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    escrow_account: &InterfaceAccount<'info, TokenAccount>,
+    labour_token_account: &InterfaceAccount<'info, TokenAccount>,
+    treasury_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    mint: &InterfaceAccount<'info, Mint>,
+    project: &Account<'info, Project>,
+    project_bump: u8,
+    wage: u64,
+) -> Result<()> {
+    transfer_from_escrow(
+        token_program,
+        escrow_account,
+        labour_token_account,
+        mint,
+        project,
+        project_bump,
+        wage,
+    )?;
+
+    // The treasury is only needed once a fee is actually charged
+    let fee = protocol_fee(wage, project.fee_bps).ok_or(ErrorCode::CalculationError)?;
+    if fee > 0 {
+        let treasury_account = treasury_account.ok_or(ErrorCode::TreasuryRequired)?;
+        transfer_from_escrow(
+            token_program,
+            escrow_account,
+            treasury_account,
+            mint,
+            project,
+            project_bump,
+            fee,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Moves `amount` lamports out of a program owned account, keeping it rent exempt
+pub fn withdraw_lamports<'info>(
+    source_info: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(source_info.data_len());
+    let available = source_info.lamports().saturating_sub(rent_exempt);
+
+    require!(available >= amount, ErrorCode::InsufficientFunds);
+
+    **source_info.try_borrow_mut_lamports()? -= amount;
+    **destination.try_borrow_mut_lamports()? += amount;
+
+    Ok(())
+}
+
+// Native SOL counterpart of `pay_wage`, the protocol fee goes to the native treasury
+pub fn pay_native_wage<'info>(
+    escrow_info: &AccountInfo<'info>,
+    labour_wallet: &AccountInfo<'info>,
+    treasury_info: Option<&AccountInfo<'info>>,
+    project: &Account<'info, Project>,
+    wage: u64,
+) -> Result<()> {
+    withdraw_lamports(escrow_info, labour_wallet, wage)?;
+
+    let fee = protocol_fee(wage, project.fee_bps).ok_or(ErrorCode::CalculationError)?;
+    if fee > 0 {
+        let treasury_info = treasury_info.ok_or(ErrorCode::TreasuryRequired)?;
+        withdraw_lamports(escrow_info, treasury_info, fee)?;
+    }
+
+    Ok(())
+}
+
+// Records the settlement of the day following `days_worked`, it counts as paid unless rejected
+pub fn record_work_day(
+    work_verification: &mut WorkVerification,
+    assignment: &mut Assignment,
+    project: &mut Project,
+    paid: bool,
+) -> Result<()> {
+    if paid {
+        work_verification.payment_processed = true;
+        assignment.days_paid = assignment
+            .days_paid
+            .checked_add(1)
+            .ok_or(ErrorCode::CalculationError)?;
+        project.days_paid = project
+            .days_paid
+            .checked_add(1)
+            .ok_or(ErrorCode::CalculationError)?;
+    } else {
+        work_verification.rejected = true;
+    }
+
+    // The day is settled either way, so the labour can move on to the next one
+    assignment.days_worked = assignment
+        .days_worked
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    project.pending_verifications = project
+        .pending_verifications
+        .checked_sub(1)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
+
+// Pays the day following `days_worked` if either side verified it and it is still unpaid,
+// `pay` releases the wage from whichever escrow the project uses
+pub fn settle_pending_work_day<'info>(
+    work_verification_info: &AccountInfo<'info>,
+    assignment: &mut Account<'info, Assignment>,
+    project: &mut Account<'info, Project>,
+    pay: impl FnOnce(&Account<'info, Project>, u64) -> Result<()>,
+) -> Result<()> {
+    if work_verification_info.data_is_empty() {
+        return Ok(());
+    }
+
+    require_keys_eq!(*work_verification_info.owner, crate::ID, ErrorCode::InvalidWorkVerification);
+
+    let mut work_verification =
+        WorkVerification::try_deserialize(&mut &work_verification_info.try_borrow_data()?[..])?;
+
+    let verified = work_verification.labour_verified || work_verification.manager_verified;
+    if !verified || work_verification.payment_processed {
+        return Ok(());
+    }
+
+    require!(!work_verification.disputed, ErrorCode::DisputeOpen);
+
+    let payment_amount = project
+        .wage_for(work_verification.hours_worked)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    pay(project, payment_amount)?;
+
+    record_work_day(&mut work_verification, assignment, project, true)?;
+    work_verification.try_serialize(&mut &mut work_verification_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+// Manager approval of a day the labour verified, shared by the token and native escrows
+pub fn settle_approved_work_day<'info>(
+    work_verification: &mut WorkVerification,
+    assignment: &mut Assignment,
+    project: &mut Account<'info, Project>,
+    hours_worked: u8,
+    pay: impl FnOnce(&Account<'info, Project>, u64) -> Result<()>,
+) -> Result<()> {
+    require!(!work_verification.manager_verified, ErrorCode::AlreadyVerified);
+    require!(!work_verification.disputed, ErrorCode::DisputeOpen);
+
+    // The manager must agree to the hours the labour recorded, otherwise they dispute the day
+    require!(work_verification.hours_worked == hours_worked, ErrorCode::HoursMismatch);
+
+    require!(
+        work_verification.day_number == assignment.days_worked + 1,
+        ErrorCode::InvalidDaySequence
+    );
+
+    work_verification.manager_verified = true;
+    work_verification.timestamp = Clock::get()?.unix_timestamp;
+
+    // Process payment if both parties have verified
+    if work_verification.labour_verified {
+        let payment_amount = project
+            .wage_for(work_verification.hours_worked)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        msg!("Payment amount: {}", payment_amount);
+
+        pay(project, payment_amount)?;
+
+        record_work_day(work_verification, assignment, project, true)?;
+    }
+
+    Ok(())
+}
+
+// Pays a labour verified day the manager left unanswered past the approval window
+pub fn settle_claimed_work_day<'info>(
+    work_verification: &mut WorkVerification,
+    assignment: &mut Assignment,
+    project: &mut Account<'info, Project>,
+    approval_window: i64,
+    pay: impl FnOnce(&Account<'info, Project>, u64) -> Result<()>,
+) -> Result<()> {
+    require!(work_verification.labour_verified, ErrorCode::WorkNotVerified);
+    require!(!work_verification.manager_verified, ErrorCode::AlreadyVerified);
+    require!(!work_verification.payment_processed, ErrorCode::PaymentAlreadyProcessed);
+    require!(!work_verification.disputed, ErrorCode::DisputeOpen);
+
+    let now = Clock::get()?.unix_timestamp;
+    let deadline = work_verification
+        .timestamp
+        .checked_add(approval_window)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    require!(now >= deadline, ErrorCode::ApprovalWindowNotElapsed);
+
+    // Without an answer from the manager the hours recorded by the labour stand
+    let payment_amount = project
+        .wage_for(work_verification.hours_worked)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!("Manager did not answer within the approval window, releasing {}", payment_amount);
+
+    pay(project, payment_amount)?;
+
+    record_work_day(work_verification, assignment, project, true)
+}
+
+// Pays a day the manager logged once the labour confirms it
+pub fn settle_confirmed_work_day<'info>(
+    work_verification: &mut WorkVerification,
+    assignment: &mut Assignment,
+    project: &mut Account<'info, Project>,
+    hours_worked: u8,
+    pay: impl FnOnce(&Account<'info, Project>, u64) -> Result<()>,
+) -> Result<()> {
+    require!(work_verification.manager_verified, ErrorCode::WorkNotLogged);
+    require!(!work_verification.labour_verified, ErrorCode::AlreadyVerified);
+    require!(!work_verification.disputed, ErrorCode::DisputeOpen);
+
+    // The labour must agree to the hours the manager logged, otherwise they dispute the day
+    require!(work_verification.hours_worked == hours_worked, ErrorCode::HoursMismatch);
+
+    // Both sides have now verified the day, release the payment
+    let payment_amount = project
+        .wage_for(work_verification.hours_worked)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    pay(project, payment_amount)?;
+
+    work_verification.labour_verified = true;
+    work_verification.timestamp = Clock::get()?.unix_timestamp;
+
+    record_work_day(work_verification, assignment, project, true)
+}
+
+// Applies an admin ruling to a disputed day and keeps it on the dispute record
+pub fn settle_dispute<'info>(
+    dispute: &mut Dispute,
+    work_verification: &mut WorkVerification,
+    assignment: &mut Assignment,
+    project: &mut Account<'info, Project>,
+    ruling: DisputeRuling,
+    resolved_by: Pubkey,
+    pay: impl FnOnce(&Account<'info, Project>, u64) -> Result<()>,
+) -> Result<()> {
+    require!(!work_verification.payment_processed, ErrorCode::PaymentAlreadyProcessed);
+
+    let wage = project
+        .wage_for(work_verification.hours_worked)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    let payment_amount = match ruling {
+        DisputeRuling::PayLabour => wage,
+        DisputeRuling::Split => wage / 2,
+        DisputeRuling::RejectDay => 0,
+    };
+
+    if payment_amount > 0 {
+        pay(project, payment_amount)?;
+    }
+
+    work_verification.disputed = false;
+    record_work_day(
+        work_verification,
+        assignment,
+        project,
+        ruling != DisputeRuling::RejectDay,
+    )?;
+
+    dispute.status = DisputeStatus::Resolved;
+    dispute.ruling = Some(ruling);
+    dispute.resolved_by = Some(resolved_by);
+    dispute.resolved_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+// Approves and pays each (assignment, work verification, labour destination) triple with the
+// hours the manager states for it, `pay` returns the labour wallet it paid
+pub fn settle_approved_work_days<'info>(
+    work_days: &'info [AccountInfo<'info>],
+    project: &mut Account<'info, Project>,
+    hours_worked: Vec<u8>,
+    mut pay: impl FnMut(&Account<'info, Project>, &'info AccountInfo<'info>, u64) -> Result<Pubkey>,
+) -> Result<()> {
+    let triples = work_days.chunks_exact(ACCOUNTS_PER_WORK_DAY);
+
+    require!(
+        !work_days.is_empty() && triples.remainder().is_empty(),
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    // The manager states the hours of every day, as approve_work_day does for one
+    require!(
+        hours_worked.len() == triples.len(),
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    let project_key = project.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    for (work_day, hours_worked) in triples.zip(hours_worked) {
+        let mut assignment = Account::<Assignment>::try_from(&work_day[0])?;
+        let mut work_verification = Account::<WorkVerification>::try_from(&work_day[1])?;
+
+        // Both accounts are program owned, so matching fields imply the same PDAs approve_work_day derives
+        require!(assignment.project == project_key, ErrorCode::InvalidProject);
+        require!(work_verification.project == project_key, ErrorCode::InvalidProject);
+        require!(work_verification.labour == assignment.labour, ErrorCode::InvalidLabour);
+        require!(
+            work_verification.day_number == assignment.days_worked + 1,
+            ErrorCode::InvalidDaySequence
+        );
+
+        require!(work_verification.labour_verified, ErrorCode::WorkNotVerified);
+        require!(!work_verification.manager_verified, ErrorCode::AlreadyVerified);
+        require!(!work_verification.disputed, ErrorCode::DisputeOpen);
+        require!(work_verification.hours_worked == hours_worked, ErrorCode::HoursMismatch);
+
+        let payment_amount = project
+            .wage_for(work_verification.hours_worked)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        let labour_wallet = pay(project, &work_day[2], payment_amount)?;
+
+        // The labour account is the user PDA of the wallet being paid
+        let (labour_account, _) = Pubkey::find_program_address(
+            &[USER_STATE.as_bytes(), labour_wallet.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(labour_account, assignment.labour, ErrorCode::WrongOwner);
+
+        work_verification.manager_verified = true;
+        work_verification.timestamp = now;
+        record_work_day(&mut work_verification, &mut assignment, project, true)?;
+
+        // Persist right away so a day repeated later in the batch fails the checks above
+        work_verification.exit(&crate::ID)?;
+        assignment.exit(&crate::ID)?;
+    }
+
+    msg!("Approved {} work days", work_days.len() / ACCOUNTS_PER_WORK_DAY);
+
+    Ok(())
+}
+
+// Pays the day still pending for the labour, or stops their stream, then ends the assignment
+// and frees the slot so the manager can hire a replacement once the escrow covers it again
+pub fn settle_termination<'info>(
+    work_verification_info: &AccountInfo<'info>,
+    assignment: &mut Account<'info, Assignment>,
+    project: &mut Account<'info, Project>,
+    stream: Option<&mut Account<'info, Stream>>,
+    reason: TerminationReason,
+    pay: impl FnOnce(&Account<'info, Project>, u64) -> Result<()>,
+) -> Result<()> {
+    require!(
+        project.status == ProjectStatus::InProgress || project.status == ProjectStatus::Open,
+        ErrorCode::ProjectNotActive
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if assignment.streaming {
+        // Wages streamed so far stay withdrawable, the same as stop_stream
+        let stream = stream.ok_or(ErrorCode::StreamRequired)?;
+        stream.checkpoint(now).ok_or(ErrorCode::CalculationError)?;
+        stream.stopped = true;
+
+        project.release_stream(stream).ok_or(ErrorCode::CalculationError)?;
+    } else {
+        settle_pending_work_day(work_verification_info, assignment, project, pay)?;
+
+        project
+            .release_daily_labourer(assignment)
+            .ok_or(ErrorCode::CalculationError)?;
+    }
+
+    assignment.active = false;
+    assignment.ended_at = now;
+    assignment.termination_reason = Some(reason);
+
+    project.labour_count = project
+        .labour_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    if project.status == ProjectStatus::InProgress {
+        project.status = ProjectStatus::Open;
+    }
+
+    Ok(())
+}
+
+// Records a bonus once `pay` has sent it, `escrow_balance` is what the escrow holds for wages.
+// Escrow bonuses may only spend what is left after every unpaid day or milestone.
+#[allow(clippy::too_many_arguments)]
+pub fn settle_bonus<'info>(
+    bonus: &mut Bonus,
+    assignment: &mut Account<'info, Assignment>,
+    project: &Account<'info, Project>,
+    escrow_balance: u64,
+    amount: u64,
+    memo_uri: String,
+    source: BonusSource,
+    pay: impl FnOnce(&BonusSource) -> Result<()>,
+) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidBonusAmount);
+    require!(memo_uri.len() <= MAX_URI_LENGTH, ErrorCode::MemoUriTooLong);
+
+    if source == BonusSource::EscrowSurplus {
+        // A closed project only still owes what its close held back, cancellation fees included
+        let outstanding = if project.is_finished() {
+            project.settlement_holdback()
+        } else {
+            project.outstanding_obligation()
+        }
+        .ok_or(ErrorCode::CalculationError)?;
+        let surplus = escrow_balance
+            .checked_sub(outstanding)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+
+        require!(amount <= surplus, ErrorCode::InsufficientEscrowSurplus);
+    }
+
+    pay(&source)?;
+
+    bonus.assignment = assignment.key();
+    bonus.project = project.key();
+    bonus.labour = assignment.labour;
+    bonus.amount = amount;
+    bonus.memo_uri = memo_uri;
+    bonus.source = source;
+    bonus.timestamp = Clock::get()?.unix_timestamp;
+
+    assignment.bonus_count = assignment
+        .bonus_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    assignment.bonus_total = assignment
+        .bonus_total
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
+
+// Funds and records a new milestone, `deposit` moves the amount plus the protocol fee into escrow
+pub fn fund_milestone<'info>(
+    milestone: &mut Account<'info, Milestone>,
+    project: &mut Account<'info, Project>,
+    amount: u64,
+    description_uri: String,
+    deposit: impl FnOnce(u64) -> Result<()>,
+) -> Result<()> {
+    require!(
+        project.status == ProjectStatus::InProgress || project.status == ProjectStatus::Open,
+        ErrorCode::ProjectNotActive
+    );
+
+    require!(amount > 0, ErrorCode::InvalidMilestoneAmount);
+    require!(
+        description_uri.len() <= MAX_URI_LENGTH,
+        ErrorCode::MilestoneUriTooLong
+    );
+
+    let deposit_amount = protocol_fee(amount, project.fee_bps)
+        .and_then(|fee| amount.checked_add(fee))
+        .ok_or(ErrorCode::CalculationError)?;
+
+    // Fund the milestone up front, like daily rate projects fund every day at creation
+    deposit(deposit_amount)?;
+
+    milestone.project = project.key();
+    milestone.index = project.milestone_count;
+    milestone.amount = amount;
+    milestone.description_uri = description_uri;
+    milestone.assignee = None;
+    milestone.submission_uri = String::new();
+    milestone.labour_verified = false;
+    milestone.manager_verified = false;
+    milestone.payment_processed = false;
+    milestone.timestamp = Clock::get()?.unix_timestamp;
+
+    project.milestone_count = project
+        .milestone_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    project.milestone_total = project
+        .milestone_total
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
+
+// Pays a submitted milestone on the manager's approval
+pub fn settle_milestone<'info>(
+    milestone: &mut Milestone,
+    project: &mut Account<'info, Project>,
+    pay: impl FnOnce(&Account<'info, Project>, u64) -> Result<()>,
+) -> Result<()> {
+    require!(milestone.labour_verified, ErrorCode::MilestoneNotSubmitted);
+    require!(!milestone.manager_verified, ErrorCode::AlreadyVerified);
+
+    let payment_amount = milestone.amount;
+
+    pay(project, payment_amount)?;
+
+    milestone.manager_verified = true;
+    milestone.payment_processed = true;
+    milestone.timestamp = Clock::get()?.unix_timestamp;
+
+    project.pending_verifications = project
+        .pending_verifications
+        .checked_sub(1)
+        .ok_or(ErrorCode::CalculationError)?;
+    project.pending_milestone_total = project
+        .pending_milestone_total
+        .checked_sub(payment_amount)
+        .ok_or(ErrorCode::CalculationError)?;
+    project.milestone_paid = project
+        .milestone_paid
+        .checked_add(payment_amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    Ok(())
+}
+
+// Sets the fee owed to each labour still on a cancelled project, returns what is left to refund
+pub fn reserve_cancellation_fees(
+    project: &mut Project,
+    refund_amount: u64,
+    cancellation_fee: u64,
+) -> Result<u64> {
+    require!(
+        project.status == ProjectStatus::Cancelled,
+        ErrorCode::WrongProjectStatus
+    );
+
+    // A cancelled project can be closed again, its fees are then already part of the holdback
+    require!(project.cancellation_fee == 0, ErrorCode::CancellationFeesAlreadyReserved);
+
+    let total_fees = cancellation_fee
+        .checked_mul(project.labour_count as u64)
+        .ok_or(ErrorCode::CalculationError)?;
+    let refund_amount = refund_amount
+        .checked_sub(total_fees)
+        .ok_or(ErrorCode::InsufficientFunds)?;
+
+    project.cancellation_fee = cancellation_fee;
+
+    Ok(refund_amount)
+}
+
+// Every certification type in `required` needs a valid certification of the labour among `certification_infos`
+pub fn require_certifications(
+    required: &[u16],
+    labour_account: Pubkey,
+    certification_infos: &[AccountInfo],
+    now: i64,
+) -> Result<()> {
+    for certification_type in required {
+        let certified = certification_infos.iter().any(|certification_info| {
+            if *certification_info.owner != crate::ID {
+                return false;
+            }
+
+            let Ok(data) = certification_info.try_borrow_data() else {
+                return false;
+            };
+
+            match Certification::try_deserialize(&mut &data[..]) {
+                Ok(certification) => {
+                    certification.user == labour_account &&
+                        certification.certification_type == *certification_type &&
+                        certification.is_valid(now)
+                }
+                Err(_) => false,
+            }
+        });
+
+        require!(certified, ErrorCode::MissingCertification);
+    }
+
+    Ok(())
+}
+
+// Pays the project's cancellation fee for each (assignment, labour destination) pair,
+// `pay` releases the fee from whichever escrow the project uses and returns the labour wallet
+pub fn settle_cancellation_fees<'info>(
+    pairs: &'info [AccountInfo<'info>],
+    project: &mut Account<'info, Project>,
+    mut pay: impl FnMut(&Account<'info, Project>, &'info AccountInfo<'info>) -> Result<Pubkey>,
+) -> Result<()> {
+    let assignments = pairs.chunks_exact(2);
+
+    // Assignments can be spread over several calls, there is no need to pass them all at once
+    require!(
+        assignments.remainder().is_empty() &&
+        assignments.len() <= project.labour_count as usize,
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    let project_key = project.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    for pair in assignments {
+        let mut assignment = Account::<Assignment>::try_from(&pair[0])?;
+
+        require!(assignment.project == project_key, ErrorCode::InvalidProject);
+        require!(assignment.active, ErrorCode::AssignmentNotActive);
+        require!(!assignment.cancellation_fee_paid, ErrorCode::CancellationFeeAlreadyPaid);
+
+        let labour_wallet = pay(project, &pair[1])?;
+
+        // The labour account is the user PDA of the wallet being paid
+        let (labour_account, _) = Pubkey::find_program_address(
+            &[USER_STATE.as_bytes(), labour_wallet.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(labour_account, assignment.labour, ErrorCode::WrongOwner);
+
+        // Persist right away so an assignment repeated later in the list fails the checks above
+        assignment.active = false;
+        assignment.cancellation_fee_paid = true;
+        assignment.ended_at = now;
+        assignment.exit(&crate::ID)?;
+
+        if !assignment.streaming {
+            project
+                .release_daily_labourer(&assignment)
+                .ok_or(ErrorCode::CalculationError)?;
+        }
+        project.labour_count = project
+            .labour_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::CalculationError)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::states::tests::{assignment, certification, daily_rate_project};
+    use crate::states::NativeEscrow;
+    use anchor_lang::solana_program::entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE};
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use anchor_lang::{AccountsExit, Bumps};
+    use std::cell::Cell;
+    use std::collections::BTreeSet;
+    use std::sync::Once;
+
+    thread_local! {
+        static NOW: Cell<i64> = const { Cell::new(0) };
+    }
+
+    // Serves the clock and rent sysvars that the helpers read outside of the runtime
+    struct Sysvars;
+
+    impl SyscallStubs for Sysvars {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: NOW.with(Cell::get),
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            0
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            0
+        }
+
+        // Only the system program's create_account is served, which is what `init` issues
+        // for an account without lamports
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            assert_eq!(instruction.program_id, anchor_lang::system_program::ID);
+            let data = &instruction.data;
+            assert_eq!(data[..4], [0; 4], "only create_account is supported");
+            let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+            let space = u64::from_le_bytes(data[12..20].try_into().unwrap());
+            let owner = Pubkey::try_from(&data[20..52]).unwrap();
+
+            let find = |meta: &AccountMeta| {
+                account_infos
+                    .iter()
+                    .find(|info| *info.key == meta.pubkey)
+                    .unwrap()
+            };
+            let (from, to) = (find(&instruction.accounts[0]), find(&instruction.accounts[1]));
+            **from.try_borrow_mut_lamports()? -= lamports;
+            **to.try_borrow_mut_lamports()? += lamports;
+            to.realloc(space as usize, true)?;
+            to.assign(&owner);
+            Ok(())
+        }
+    }
+
+    pub(crate) fn set_now(now: i64) {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(Sysvars));
+        });
+        NOW.with(|cell| cell.set(now));
+    }
+
+    // Mirrors the runtime's input layout, which `realloc` relies on when an account is closed
+    #[repr(C)]
+    struct SerializedKey {
+        original_data_len: u32,
+        key: Pubkey,
+    }
+
+    fn leak_account(
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        is_signer: bool,
+    ) -> &'static AccountInfo<'static> {
+        let serialized_key = Box::leak(Box::new(SerializedKey {
+            original_data_len: data.len() as u32,
+            key,
+        }));
+
+        // The eight bytes before the data hold its length, the data may grow by as much as
+        // the runtime allows
+        let capacity = data.len() + MAX_PERMITTED_DATA_INCREASE;
+        let words = Box::leak(vec![0u64; 1 + capacity.div_ceil(8)].into_boxed_slice());
+        let bytes = unsafe { std::slice::from_raw_parts_mut(words.as_mut_ptr() as *mut u8, words.len() * 8) };
+        let data_buffer = &mut bytes[8..8 + data.len()];
+        data_buffer.copy_from_slice(&data);
+
+        Box::leak(Box::new(AccountInfo::new(
+            &serialized_key.key,
+            is_signer,
+            true,
+            Box::leak(Box::new(lamports)),
+            data_buffer,
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )))
+    }
+
+    fn account_info(owner: Pubkey, lamports: u64, data: Vec<u8>) -> &'static AccountInfo<'static> {
+        leak_account(Pubkey::new_unique(), owner, lamports, data, false)
+    }
+
+    // Rent exempt program account at `key` holding `value`
+    pub(crate) fn program_account_at<T: AccountSerialize>(
+        key: Pubkey,
+        value: &T,
+    ) -> &'static AccountInfo<'static> {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        let rent_exempt = Rent::default().minimum_balance(data.len());
+        leak_account(key, crate::ID, rent_exempt, data, false)
+    }
+
+    // Like `program_account_at`, but sized to `space` bytes as `init` would
+    // allocate it, so handlers can push to its vectors
+    pub(crate) fn program_account_with_space<T: AccountSerialize>(
+        key: Pubkey,
+        value: &T,
+        space: usize,
+    ) -> &'static AccountInfo<'static> {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        data.resize(space, 0);
+        let rent_exempt = Rent::default().minimum_balance(space);
+        leak_account(key, crate::ID, rent_exempt, data, false)
+    }
+
+    pub(crate) fn program(id: Pubkey) -> &'static AccountInfo<'static> {
+        let mut info = leak_account(id, Pubkey::default(), 1, Vec::new(), false).clone();
+        info.executable = true;
+        Box::leak(Box::new(info))
+    }
+
+    pub(crate) fn signer(key: Pubkey) -> &'static AccountInfo<'static> {
+        leak_account(key, anchor_lang::system_program::ID, 1_000_000_000, Vec::new(), true)
+    }
+
+    pub(crate) fn system_account(key: Pubkey) -> &'static AccountInfo<'static> {
+        leak_account(key, anchor_lang::system_program::ID, 0, Vec::new(), false)
+    }
+
+    pub(crate) fn pda(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &crate::ID).0
+    }
+
+    pub(crate) fn read<T: AccountDeserialize>(info: &AccountInfo) -> T {
+        T::try_deserialize(&mut &info.try_borrow_data().unwrap()[..]).unwrap()
+    }
+
+    // Runs an instruction the way the entrypoint does, validating the accounts, calling the
+    // handler and writing the accounts back on exit
+    pub(crate) fn process<T>(
+        accounts: &[&'static AccountInfo<'static>],
+        handler: impl FnOnce(Context<'_, '_, 'static, 'static, T>) -> Result<()>,
+    ) -> Result<()>
+    where
+        T: Accounts<'static, <T as Bumps>::Bumps> + Bumps + AccountsExit<'static>,
+        <T as Bumps>::Bumps: Default,
+    {
+        process_with(accounts, &[], handler)
+    }
+
+    // Like `process`, for instructions whose accounts read the arguments in `ix_data`
+    pub(crate) fn process_with<T>(
+        accounts: &[&'static AccountInfo<'static>],
+        ix_data: &[u8],
+        handler: impl FnOnce(Context<'_, '_, 'static, 'static, T>) -> Result<()>,
+    ) -> Result<()>
+    where
+        T: Accounts<'static, <T as Bumps>::Bumps> + Bumps + AccountsExit<'static>,
+        <T as Bumps>::Bumps: Default,
+    {
+        set_now(0);
+        let mut infos: &'static [AccountInfo<'static>] =
+            Box::leak(accounts.iter().map(|info| (*info).clone()).collect());
+        let mut bumps = Default::default();
+        let mut accounts =
+            T::try_accounts(&crate::ID, &mut infos, ix_data, &mut bumps, &mut BTreeSet::new())?;
+
+        handler(Context::new(&crate::ID, &mut accounts, infos, bumps))?;
+        accounts.exit(&crate::ID)
+    }
+
+    fn program_account<T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+        value: &T,
+    ) -> Account<'static, T> {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        Account::try_from(account_info(crate::ID, 1, data)).unwrap()
+    }
+
+    // Program owned lamport account holding `balance` on top of its rent exemption
+    fn lamport_account(balance: u64) -> &'static AccountInfo<'static> {
+        let data = vec![0; 8 + NativeEscrow::INIT_SPACE];
+        let rent_exempt = Rent::default().minimum_balance(data.len());
+        account_info(crate::ID, rent_exempt + balance, data)
+    }
+
+    fn wallet() -> &'static AccountInfo<'static> {
+        account_info(anchor_lang::system_program::ID, 0, Vec::new())
+    }
+
+    pub(crate) fn work_verification(hours_worked: u8) -> WorkVerification {
+        WorkVerification {
+            project: Pubkey::default(),
+            labour: Pubkey::default(),
+            day_number: 1,
+            hours_worked,
+            manager_verified: false,
+            labour_verified: true,
+            metadata_uri: String::new(),
+            timestamp: 0,
+            payment_processed: false,
+            disputed: false,
+            rejected: false,
+            rent_payer: Pubkey::default(),
+        }
+    }
+
+    fn dispute() -> Dispute {
+        Dispute {
+            work_verification: Pubkey::default(),
+            project: Pubkey::default(),
+            labour: Pubkey::default(),
+            opened_by: Pubkey::default(),
+            labour_evidence_uri: String::new(),
+            manager_evidence_uri: String::new(),
+            status: DisputeStatus::Open,
+            ruling: None,
+            resolved_by: None,
+            timestamp: 0,
+            resolved_at: 0,
+            rent_payer: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn decay_within_a_half_life_is_linear() {
+        // Half way between the first and second halving
+        assert_eq!(decay(1_000, 150, 100), Some(375));
+        assert_eq!(decay(1_000, -10, 100), Some(1_000));
+    }
+
+    #[test]
+    fn decay_rejects_a_zero_half_life() {
+        assert_eq!(decay(1_000, 10, 0), None);
+    }
+
+    #[test]
+    fn protocol_fee_rounds_down() {
+        assert_eq!(protocol_fee(1_000, 100), Some(10));
+        assert_eq!(protocol_fee(150, 100), Some(1));
+        assert_eq!(protocol_fee(1_000, 0), Some(0));
+    }
+
+    #[test]
+    fn native_wage_routes_the_fee_to_the_native_treasury() {
+        set_now(0);
+        let project = program_account(&daily_rate_project());
+        let escrow = lamport_account(1_010);
+        let treasury = lamport_account(0);
+        let labour = wallet();
+
+        pay_native_wage(escrow, labour, Some(treasury), &project, 1_000).unwrap();
+
+        assert_eq!(labour.lamports(), 1_000);
+        assert_eq!(treasury.lamports(), lamport_account(10).lamports());
+        assert_eq!(escrow.lamports(), lamport_account(0).lamports());
+    }
+
+    #[test]
+    fn native_wage_with_a_fee_requires_the_treasury() {
+        set_now(0);
+        let project = program_account(&daily_rate_project());
+        let escrow = lamport_account(1_010);
+
+        assert_eq!(
+            pay_native_wage(escrow, wallet(), None, &project, 1_000).unwrap_err(),
+            ErrorCode::TreasuryRequired.into()
+        );
+    }
+
+    #[test]
+    fn native_wage_keeps_the_escrow_rent_exempt() {
+        set_now(0);
+        let mut project = daily_rate_project();
+        project.fee_bps = 0;
+        let project = program_account(&project);
+        let escrow = lamport_account(999);
+
+        assert_eq!(
+            pay_native_wage(escrow, wallet(), None, &project, 1_000).unwrap_err(),
+            ErrorCode::InsufficientFunds.into()
+        );
+    }
+
+    #[test]
+    fn rejected_day_is_refunded_by_closing_the_cancelled_project_again() {
+        set_now(0);
+        let mut project = daily_rate_project();
+        project.pending_verifications = 1;
+        let mut project = program_account(&project);
+
+        project.finish(ProjectStatus::Cancelled).unwrap();
+        assert_eq!(project.settlement_holdback(), Some(101));
+
+        let mut work_verification = work_verification(HOURS_PER_DAY);
+        work_verification.disputed = true;
+        settle_dispute(
+            &mut dispute(),
+            &mut work_verification,
+            &mut assignment(0),
+            &mut project,
+            DisputeRuling::RejectDay,
+            Pubkey::default(),
+            |_, _| panic!("a rejected day is not paid"),
+        )
+        .unwrap();
+
+        // Nothing is held back anymore, closing again refunds the whole escrow
+        assert_eq!(project.settlement_holdback(), Some(0));
+        assert!(project.finish(ProjectStatus::Cancelled).is_ok());
+    }
+
+    #[test]
+    fn cancellation_fees_are_reserved_once() {
+        let mut project = daily_rate_project();
+        project.finish(ProjectStatus::Cancelled).unwrap();
+
+        assert_eq!(reserve_cancellation_fees(&mut project, 1_000, 50).unwrap(), 900);
+        assert_eq!(
+            reserve_cancellation_fees(&mut project, 1_000, 50).unwrap_err(),
+            ErrorCode::CancellationFeesAlreadyReserved.into()
+        );
+    }
+
+    #[test]
+    fn surplus_bonus_of_a_cancelled_project_leaves_its_holdback() {
+        set_now(0);
+        let mut project = daily_rate_project();
+        project.finish(ProjectStatus::Cancelled).unwrap();
+        reserve_cancellation_fees(&mut project, 1_000, 50).unwrap();
+        let project = program_account(&project);
+        let mut assignment = program_account(&assignment(0));
+        let mut bonus = Bonus {
+            assignment: Pubkey::default(),
+            project: Pubkey::default(),
+            labour: Pubkey::default(),
+            amount: 0,
+            memo_uri: String::new(),
+            source: BonusSource::EscrowSurplus,
+            timestamp: 0,
+        };
+
+        // Both labour are still owed their cancellation fee
+        assert_eq!(
+            settle_bonus(
+                &mut bonus,
+                &mut assignment,
+                &project,
+                120,
+                30,
+                String::new(),
+                BonusSource::EscrowSurplus,
+                |_| Ok(()),
+            )
+            .unwrap_err(),
+            ErrorCode::InsufficientEscrowSurplus.into()
+        );
+
+        settle_bonus(
+            &mut bonus,
+            &mut assignment,
+            &project,
+            120,
+            20,
+            String::new(),
+            BonusSource::EscrowSurplus,
+            |_| Ok(()),
+        )
+        .unwrap();
+        assert_eq!(assignment.bonus_total, 20);
+    }
+
+    #[test]
+    fn labour_confirms_the_logged_hours() {
+        set_now(0);
+        let mut project = program_account(&daily_rate_project());
+        project.pending_verifications = 1;
+        let mut work_verification = work_verification(4);
+        work_verification.manager_verified = true;
+        work_verification.labour_verified = false;
+        let mut worker = assignment(1);
+
+        assert_eq!(
+            settle_confirmed_work_day(
+                &mut work_verification,
+                &mut worker,
+                &mut project,
+                HOURS_PER_DAY,
+                |_, _| panic!("disagreeing hours are not paid"),
+            )
+            .unwrap_err(),
+            ErrorCode::HoursMismatch.into()
+        );
+
+        let mut paid = 0;
+        settle_confirmed_work_day(&mut work_verification, &mut worker, &mut project, 4, |_, wage| {
+            paid = wage;
+            Ok(())
+        })
+        .unwrap();
+
+        // Half a day at 100 a day
+        assert_eq!(paid, 50);
+        assert!(work_verification.payment_processed);
+        assert_eq!(project.pending_verifications, 0);
+    }
+
+    #[test]
+    fn split_ruling_pays_half_the_wage_and_settles_the_day() {
+        set_now(42);
+        let mut project = daily_rate_project();
+        project.pending_verifications = 1;
+        let mut project = program_account(&project);
+        let mut dispute = dispute();
+        let mut work_verification = work_verification(HOURS_PER_DAY);
+        work_verification.disputed = true;
+        let mut worker = assignment(0);
+        let admin = Pubkey::new_unique();
+
+        let mut paid = 0;
+        settle_dispute(
+            &mut dispute,
+            &mut work_verification,
+            &mut worker,
+            &mut project,
+            DisputeRuling::Split,
+            admin,
+            |_, wage| {
+                paid = wage;
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(paid, 50);
+        assert!(dispute.status == DisputeStatus::Resolved);
+        assert!(dispute.ruling == Some(DisputeRuling::Split));
+        assert_eq!(dispute.resolved_by, Some(admin));
+        assert_eq!(dispute.resolved_at, 42);
+        assert!(!work_verification.disputed && work_verification.payment_processed);
+        assert_eq!(worker.days_worked, 1);
+        assert_eq!(project.pending_verifications, 0);
+    }
+
+    #[test]
+    fn paid_day_cannot_be_ruled_on() {
+        set_now(0);
+        let mut project = program_account(&daily_rate_project());
+        let mut work_verification = work_verification(HOURS_PER_DAY);
+        work_verification.payment_processed = true;
+
+        assert_eq!(
+            settle_dispute(
+                &mut dispute(),
+                &mut work_verification,
+                &mut assignment(0),
+                &mut project,
+                DisputeRuling::PayLabour,
+                Pubkey::default(),
+                |_, _| panic!("a paid day is not paid twice"),
+            )
+            .unwrap_err(),
+            ErrorCode::PaymentAlreadyProcessed.into()
+        );
+    }
+
+    #[test]
+    fn unanswered_day_is_claimable_once_the_window_elapses() {
+        let mut project = daily_rate_project();
+        project.pending_verifications = 1;
+        let mut project = program_account(&project);
+        let mut work_verification = work_verification(HOURS_PER_DAY);
+        work_verification.timestamp = 1_000;
+        let mut worker = assignment(0);
+
+        set_now(1_000 + DEFAULT_APPROVAL_WINDOW - 1);
+        assert_eq!(
+            settle_claimed_work_day(
+                &mut work_verification,
+                &mut worker,
+                &mut project,
+                DEFAULT_APPROVAL_WINDOW,
+                |_, _| panic!("the manager can still answer"),
+            )
+            .unwrap_err(),
+            ErrorCode::ApprovalWindowNotElapsed.into()
+        );
+
+        set_now(1_000 + DEFAULT_APPROVAL_WINDOW);
+        let mut paid = 0;
+        settle_claimed_work_day(
+            &mut work_verification,
+            &mut worker,
+            &mut project,
+            DEFAULT_APPROVAL_WINDOW,
+            |_, wage| {
+                paid = wage;
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(paid, 100);
+        assert!(work_verification.payment_processed);
+        assert_eq!(project.pending_verifications, 0);
+    }
+
+    #[test]
+    fn disputed_day_cannot_be_claimed() {
+        set_now(i64::MAX);
+        let mut project = program_account(&daily_rate_project());
+        let mut work_verification = work_verification(HOURS_PER_DAY);
+        work_verification.disputed = true;
+
+        assert_eq!(
+            settle_claimed_work_day(
+                &mut work_verification,
+                &mut assignment(0),
+                &mut project,
+                DEFAULT_APPROVAL_WINDOW,
+                |_, _| panic!("a disputed day waits for the ruling"),
+            )
+            .unwrap_err(),
+            ErrorCode::DisputeOpen.into()
+        );
+    }
+
+    // (assignment, work verification, labour wallet) of a labour's first work day on `project`
+    fn work_day(project: Pubkey, hours_worked: u8) -> [AccountInfo<'static>; 3] {
+        let wallet = Pubkey::new_unique();
+        let mut worker = assignment(0);
+        worker.project = project;
+        worker.labour = pda(&[USER_STATE.as_bytes(), wallet.as_ref()]);
+        let mut day = work_verification(hours_worked);
+        day.project = project;
+        day.labour = worker.labour;
+
+        [
+            program_account_at(Pubkey::new_unique(), &worker).clone(),
+            program_account_at(Pubkey::new_unique(), &day).clone(),
+            system_account(wallet).clone(),
+        ]
+    }
+
+    #[test]
+    fn batch_approves_and_pays_every_day() {
+        set_now(7);
+        let mut project = daily_rate_project();
+        project.pending_verifications = 2;
+        let mut project = program_account(&project);
+        let days = [work_day(project.key(), HOURS_PER_DAY), work_day(project.key(), 4)];
+        let work_days: &'static [AccountInfo<'static>] = Box::leak(days.concat().into_boxed_slice());
+
+        let mut paid = Vec::new();
+        settle_approved_work_days(work_days, &mut project, vec![HOURS_PER_DAY, 4], |_, wallet, wage| {
+            paid.push((*wallet.key, wage));
+            Ok(*wallet.key)
+        })
+        .unwrap();
+
+        assert_eq!(paid, vec![(*work_days[2].key, 100), (*work_days[5].key, 50)]);
+        assert_eq!(project.pending_verifications, 0);
+        for day in work_days.chunks(ACCOUNTS_PER_WORK_DAY) {
+            let work_verification = read::<WorkVerification>(&day[1]);
+            assert!(work_verification.manager_verified && work_verification.payment_processed);
+            assert_eq!(work_verification.timestamp, 7);
+            assert_eq!(read::<Assignment>(&day[0]).days_paid, 1);
+        }
+    }
+
+    #[test]
+    fn batch_cannot_pay_a_day_twice() {
+        set_now(0);
+        let mut project = daily_rate_project();
+        project.pending_verifications = 2;
+        let mut project = program_account(&project);
+        let day = work_day(project.key(), HOURS_PER_DAY);
+        let work_days: &'static [AccountInfo<'static>] =
+            Box::leak([day.clone(), day].concat().into_boxed_slice());
+
+        assert_eq!(
+            settle_approved_work_days(
+                work_days,
+                &mut project,
+                vec![HOURS_PER_DAY, HOURS_PER_DAY],
+                |_, wallet, _| Ok(*wallet.key),
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidDaySequence.into()
+        );
+    }
+
+    #[test]
+    fn batch_takes_whole_triples_with_their_hours() {
+        set_now(0);
+        let mut project = program_account(&daily_rate_project());
+        let day = work_day(project.key(), HOURS_PER_DAY);
+
+        assert_eq!(
+            settle_approved_work_days(
+                Box::leak(day[..2].to_vec().into_boxed_slice()),
+                &mut project,
+                vec![HOURS_PER_DAY],
+                |_, wallet, _| Ok(*wallet.key),
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidRemainingAccounts.into()
+        );
+        assert_eq!(
+            settle_approved_work_days(
+                Box::leak(day.to_vec().into_boxed_slice()),
+                &mut project,
+                Vec::new(),
+                |_, wallet, _| Ok(*wallet.key),
+            )
+            .unwrap_err(),
+            ErrorCode::InvalidRemainingAccounts.into()
+        );
+    }
+
+    #[test]
+    fn labour_only_confirms_days_the_manager_logged() {
+        set_now(0);
+        let mut project = program_account(&daily_rate_project());
+        let mut worker = assignment(0);
+
+        // Verified by the labour but never logged by the manager
+        let mut work_verification = work_verification(HOURS_PER_DAY);
+        assert_eq!(
+            settle_confirmed_work_day(
+                &mut work_verification,
+                &mut worker,
+                &mut project,
+                HOURS_PER_DAY,
+                |_, _| panic!("nothing was logged"),
+            )
+            .unwrap_err(),
+            ErrorCode::WorkNotLogged.into()
+        );
+
+        // Logged and already confirmed
+        work_verification.manager_verified = true;
+        assert_eq!(
+            settle_confirmed_work_day(
+                &mut work_verification,
+                &mut worker,
+                &mut project,
+                HOURS_PER_DAY,
+                |_, _| panic!("the day was confirmed already"),
+            )
+            .unwrap_err(),
+            ErrorCode::AlreadyVerified.into()
+        );
+    }
+
+    // Token-2022 mint, with a transfer fee of `fee_bps` capped at `maximum_fee` when given
+    fn mint(transfer_fee: Option<(u16, u64)>) -> InterfaceAccount<'static, Mint> {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token_interface::spl_token_2022::extension::{
+            transfer_fee::TransferFee, BaseStateWithExtensionsMut, ExtensionType,
+            StateWithExtensionsMut,
+        };
+
+        let extensions: &[ExtensionType] = match transfer_fee {
+            Some(_) => &[ExtensionType::TransferFeeConfig],
+            None => &[],
+        };
+        let mut data =
+            vec![0; ExtensionType::try_calculate_account_len::<MintState>(extensions).unwrap()];
+        let mut state = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        state.base = MintState {
+            decimals: 6,
+            is_initialized: true,
+            ..MintState::default()
+        };
+        state.pack_base();
+        if let Some((fee_bps, maximum_fee)) = transfer_fee {
+            let fee = TransferFee {
+                epoch: 0.into(),
+                maximum_fee: maximum_fee.into(),
+                transfer_fee_basis_points: fee_bps.into(),
+            };
+            let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+            config.older_transfer_fee = fee;
+            config.newer_transfer_fee = fee;
+        }
+        state.init_account_type().unwrap();
+        if transfer_fee.is_none() {
+            data.truncate(MintState::LEN);
+        }
+
+        let info = leak_account(
+            Pubkey::new_unique(),
+            anchor_spl::token_interface::spl_token_2022::ID,
+            0,
+            data,
+            false,
+        );
+        InterfaceAccount::try_from(info).unwrap()
+    }
+
+    #[test]
+    fn transfer_fee_is_grossed_up_so_the_amount_arrives_in_full() {
+        set_now(0);
+        let mint = mint(Some((100, u64::MAX)));
+
+        assert_eq!(inverse_transfer_fee(&mint, 9_900).unwrap(), 100);
+        assert_eq!(inverse_transfer_fee(&mint, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn transfer_fee_gross_up_stops_at_the_maximum_fee() {
+        set_now(0);
+        let mint = mint(Some((100, 50)));
+
+        assert_eq!(inverse_transfer_fee(&mint, 1_000_000).unwrap(), 50);
+    }
+
+    #[test]
+    fn mint_without_transfer_fee_is_not_grossed_up() {
+        set_now(0);
+        let mint = mint(None);
+
+        assert_eq!(inverse_transfer_fee(&mint, 1_000_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn required_certifications_must_each_be_held_and_valid() {
+        let labour = Pubkey::new_unique();
+        let certifications = [
+            program_account_at(Pubkey::new_unique(), &certification(labour, 1, 100)),
+            program_account_at(Pubkey::new_unique(), &certification(labour, 2, 100)),
+        ];
+        let infos: Vec<AccountInfo> = certifications.iter().map(|info| (*info).clone()).collect();
+
+        require_certifications(&[], labour, &[], 0).unwrap();
+        require_certifications(&[2, 1], labour, &infos, 99).unwrap();
+
+        for (required, now) in [(&[1, 3][..], 0), (&[1][..], 100)] {
+            assert_eq!(
+                require_certifications(required, labour, &infos, now).unwrap_err(),
+                ErrorCode::MissingCertification.into()
+            );
+        }
+    }
+
+    #[test]
+    fn certification_of_someone_else_or_revoked_does_not_count() {
+        let labour = Pubkey::new_unique();
+        let mut revoked = certification(labour, 1, 100);
+        revoked.revoked = true;
+
+        let mut data = Vec::new();
+        certification(labour, 1, 100).try_serialize(&mut data).unwrap();
+
+        for certification_info in [
+            program_account_at(Pubkey::new_unique(), &certification(Pubkey::new_unique(), 1, 100)),
+            program_account_at(Pubkey::new_unique(), &revoked),
+            // A valid looking copy not owned by the program
+            account_info(Pubkey::new_unique(), 1, data),
+        ] {
+            let certification_infos = std::slice::from_ref(certification_info);
+            assert_eq!(
+                require_certifications(&[1], labour, certification_infos, 0).unwrap_err(),
+                ErrorCode::MissingCertification.into()
+            );
+        }
+    }
+}